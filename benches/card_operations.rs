@@ -28,7 +28,7 @@ fn bench_card_comparison(c: &mut Criterion) {
     let card2 = Card::new(Suit::Spades, Rank::Queen);
 
     c.bench_function("card_value_comparison", |b| {
-        b.iter(|| black_box(card1.value() > card2.value()))
+        b.iter(|| black_box(card1 > card2))
     });
 }
 
@@ -38,7 +38,7 @@ fn bench_deck_operations(c: &mut Criterion) {
     let mut group = c.benchmark_group("deck_shuffle");
     group.bench_function("shuffle", |b| {
         b.iter_batched(
-            || Deck::new(),
+            Deck::new,
             |mut deck| {
                 deck.shuffle();
                 black_box(deck)
@@ -49,7 +49,7 @@ fn bench_deck_operations(c: &mut Criterion) {
 
     group.bench_function("shuffle_with_seed", |b| {
         b.iter_batched(
-            || Deck::new(),
+            Deck::new,
             |mut deck| {
                 deck.shuffle_with_seed(12345);
                 black_box(deck)
@@ -82,7 +82,7 @@ fn bench_player_hand_operations(c: &mut Criterion) {
 
     group.bench_function("add_card", |b| {
         b.iter_batched(
-            || PlayerHand::new(),
+            PlayerHand::new,
             |mut hand| {
                 let card = Card::new(Suit::Hearts, Rank::Ace);
                 hand.add_card(card);
@@ -97,7 +97,7 @@ fn bench_player_hand_operations(c: &mut Criterion) {
             || {
                 let mut hand = PlayerHand::new();
                 for i in 2..=14 {
-                    if let Some(rank) = u8_to_rank(i as u8) {
+                    if let Some(rank) = Rank::from_value(i as u8) {
                         hand.add_card(Card::new(Suit::Hearts, rank));
                     }
                 }
@@ -112,7 +112,7 @@ fn bench_player_hand_operations(c: &mut Criterion) {
         let hand = {
             let mut h = PlayerHand::new();
             for i in 2..=14 {
-                if let Some(rank) = u8_to_rank(i as u8) {
+                if let Some(rank) = Rank::from_value(i as u8) {
                     h.add_card(Card::new(Suit::Hearts, rank));
                 }
             }
@@ -149,7 +149,7 @@ fn bench_card_battle_simulation(c: &mut Criterion) {
             },
             |(mut player1, mut player2, battle_cards)| {
                 // Simulate winner taking cards
-                if let (Some(card1), Some(card2)) = (battle_cards.get(0), battle_cards.get(1)) {
+                if let (Some(card1), Some(card2)) = (battle_cards.first(), battle_cards.get(1)) {
                     if card1.value() > card2.value() {
                         for &card in &battle_cards {
                             player1.add_card(card);
@@ -167,26 +167,6 @@ fn bench_card_battle_simulation(c: &mut Criterion) {
     });
 }
 
-// Helper function for converting u8 to Rank for benchmarking
-fn u8_to_rank(value: u8) -> Option<Rank> {
-    match value {
-        2 => Some(Rank::Two),
-        3 => Some(Rank::Three),
-        4 => Some(Rank::Four),
-        5 => Some(Rank::Five),
-        6 => Some(Rank::Six),
-        7 => Some(Rank::Seven),
-        8 => Some(Rank::Eight),
-        9 => Some(Rank::Nine),
-        10 => Some(Rank::Ten),
-        11 => Some(Rank::Jack),
-        12 => Some(Rank::Queen),
-        13 => Some(Rank::King),
-        14 => Some(Rank::Ace),
-        _ => None,
-    }
-}
-
 criterion_group!(
     benches,
     bench_card_creation,
@@ -1,5 +1,6 @@
 use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
-use war_rust::cards::{Card, Deck, PlayerHand, Rank, Suit};
+use std::collections::VecDeque;
+use war_rust::cards::{Card, Deck, PlayerHand};
 use war_rust::ring_buffer::RingBuffer;
 
 fn bench_full_game_simulation(c: &mut Criterion) {
@@ -136,13 +137,109 @@ fn bench_memory_operations(c: &mut Criterion) {
     });
 }
 
+fn bench_hand_backends(c: &mut Criterion) {
+    let mut group = c.benchmark_group("hand_backend");
+
+    for rounds in [20, 500].iter() {
+        group.bench_with_input(
+            BenchmarkId::new("ring_buffer", rounds),
+            rounds,
+            |b, &rounds| b.iter(|| black_box(simulate_war_game_with::<PlayerHand>(42, rounds))),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("vec_deque", rounds),
+            rounds,
+            |b, &rounds| b.iter(|| black_box(simulate_war_game_with::<VecDequeHand>(42, rounds))),
+        );
+    }
+
+    group.finish();
+}
+
 // Helper functions
 
+/// The hand operations the simulation needs, so the same game loop can run
+/// on the stack-allocated `PlayerHand` and a heap-allocated `VecDeque`
+trait Hand: Sized {
+    fn deal(seed: u64) -> (Self, Self);
+    fn draw_card(&mut self) -> Option<Card>;
+    fn take_battle_cards(&mut self, battle_buffer: &RingBuffer<Card, 52>);
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool;
+}
+
+impl Hand for PlayerHand {
+    fn deal(seed: u64) -> (Self, Self) {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(seed);
+        deck.split()
+    }
+
+    fn draw_card(&mut self) -> Option<Card> {
+        PlayerHand::draw_card(self)
+    }
+
+    fn take_battle_cards(&mut self, battle_buffer: &RingBuffer<Card, 52>) {
+        PlayerHand::take_battle_cards(self, battle_buffer)
+    }
+
+    fn len(&self) -> usize {
+        PlayerHand::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        PlayerHand::is_empty(self)
+    }
+}
+
+/// Heap-backed equivalent of `PlayerHand` with identical card ordering
+struct VecDequeHand {
+    cards: VecDeque<Card>,
+}
+
+impl VecDequeHand {
+    fn from_player_hand(mut hand: PlayerHand) -> Self {
+        let mut cards = VecDeque::new();
+        while let Some(card) = hand.draw_card() {
+            cards.push_front(card);
+        }
+        VecDequeHand { cards }
+    }
+}
+
+impl Hand for VecDequeHand {
+    fn deal(seed: u64) -> (Self, Self) {
+        let (player1, player2) = PlayerHand::deal(seed);
+        (
+            VecDequeHand::from_player_hand(player1),
+            VecDequeHand::from_player_hand(player2),
+        )
+    }
+
+    fn draw_card(&mut self) -> Option<Card> {
+        self.cards.pop_back()
+    }
+
+    fn take_battle_cards(&mut self, battle_buffer: &RingBuffer<Card, 52>) {
+        for card in battle_buffer.iter() {
+            self.cards.push_front(card);
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+}
+
 fn setup_battle_scenario() -> (PlayerHand, PlayerHand, RingBuffer<Card, 52>) {
     let mut deck = Deck::new();
     deck.shuffle_with_seed(777);
     let (player1, player2) = deck.split();
-    let battle_buffer = RingBuffer::new(Card::new(Suit::Hearts, Rank::Two));
+    let battle_buffer = RingBuffer::new();
     (player1, player2, battle_buffer)
 }
 
@@ -151,15 +248,17 @@ fn setup_war_scenario() -> (PlayerHand, PlayerHand, RingBuffer<Card, 52>) {
     let mut deck = Deck::new();
     deck.shuffle_with_seed(888);
     let (player1, player2) = deck.split();
-    let battle_buffer = RingBuffer::new(Card::new(Suit::Hearts, Rank::Two));
+    let battle_buffer = RingBuffer::new();
     (player1, player2, battle_buffer)
 }
 
 fn simulate_war_game(seed: u64, max_rounds: usize) -> (usize, usize, usize) {
-    let mut deck = Deck::new();
-    deck.shuffle_with_seed(seed);
-    let (mut player1, mut player2) = deck.split();
-    let mut battle_buffer = RingBuffer::new(Card::new(Suit::Hearts, Rank::Two));
+    simulate_war_game_with::<PlayerHand>(seed, max_rounds)
+}
+
+fn simulate_war_game_with<H: Hand>(seed: u64, max_rounds: usize) -> (usize, usize, usize) {
+    let (mut player1, mut player2) = H::deal(seed);
+    let mut battle_buffer = RingBuffer::new();
 
     let mut rounds = 0;
 
@@ -217,7 +316,8 @@ criterion_group!(
     bench_game_setup,
     bench_battle_scenarios,
     bench_different_game_lengths,
-    bench_memory_operations
+    bench_memory_operations,
+    bench_hand_backends
 );
 
 criterion_main!(benches);
@@ -3,11 +3,11 @@ use war_rust::ring_buffer::RingBuffer;
 
 fn bench_ring_buffer_creation(c: &mut Criterion) {
     c.bench_function("ring_buffer_creation_small", |b| {
-        b.iter(|| black_box(RingBuffer::<i32, 10>::new(0)))
+        b.iter(|| black_box(RingBuffer::<i32, 10>::new()))
     });
 
     c.bench_function("ring_buffer_creation_large", |b| {
-        b.iter(|| black_box(RingBuffer::<i32, 1000>::new(0)))
+        b.iter(|| black_box(RingBuffer::<i32, 1000>::new()))
     });
 }
 
@@ -16,7 +16,7 @@ fn bench_ring_buffer_basic_ops(c: &mut Criterion) {
 
     group.bench_function("push_back", |b| {
         b.iter_batched(
-            || RingBuffer::<i32, 1000>::new(0),
+            RingBuffer::<i32, 1000>::new,
             |mut rb| {
                 for i in 0..100 {
                     rb.push_back(i);
@@ -29,7 +29,7 @@ fn bench_ring_buffer_basic_ops(c: &mut Criterion) {
 
     group.bench_function("push_front", |b| {
         b.iter_batched(
-            || RingBuffer::<i32, 1000>::new(0),
+            RingBuffer::<i32, 1000>::new,
             |mut rb| {
                 for i in 0..100 {
                     rb.push_front(i);
@@ -43,7 +43,7 @@ fn bench_ring_buffer_basic_ops(c: &mut Criterion) {
     group.bench_function("pop_back", |b| {
         b.iter_batched(
             || {
-                let mut rb = RingBuffer::<i32, 1000>::new(0);
+                let mut rb = RingBuffer::<i32, 1000>::new();
                 for i in 0..100 {
                     rb.push_back(i);
                 }
@@ -62,7 +62,7 @@ fn bench_ring_buffer_basic_ops(c: &mut Criterion) {
     group.bench_function("pop_front", |b| {
         b.iter_batched(
             || {
-                let mut rb = RingBuffer::<i32, 1000>::new(0);
+                let mut rb = RingBuffer::<i32, 1000>::new();
                 for i in 0..100 {
                     rb.push_back(i);
                 }
@@ -91,7 +91,7 @@ fn bench_ring_buffer_multiple_ops(c: &mut Criterion) {
             |b, &size| {
                 b.iter_batched(
                     || {
-                        let rb = RingBuffer::<i32, 1000>::new(0);
+                        let rb = RingBuffer::<i32, 1000>::new();
                         let data: Vec<i32> = (0..size).collect();
                         (rb, data)
                     },
@@ -110,7 +110,7 @@ fn bench_ring_buffer_multiple_ops(c: &mut Criterion) {
             |b, &size| {
                 b.iter_batched(
                     || {
-                        let rb = RingBuffer::<i32, 1000>::new(0);
+                        let rb = RingBuffer::<i32, 1000>::new();
                         let data: Vec<i32> = (0..size).collect();
                         (rb, data)
                     },
@@ -130,7 +130,7 @@ fn bench_ring_buffer_multiple_ops(c: &mut Criterion) {
 fn bench_ring_buffer_wraparound(c: &mut Criterion) {
     c.bench_function("ring_buffer_wraparound_stress", |b| {
         b.iter_batched(
-            || RingBuffer::<i32, 100>::new(0),
+            RingBuffer::<i32, 100>::new,
             |mut rb| {
                 // Fill the buffer
                 for i in 0..100 {
@@ -152,7 +152,7 @@ fn bench_ring_buffer_wraparound(c: &mut Criterion) {
 fn bench_ring_buffer_mixed_ops(c: &mut Criterion) {
     c.bench_function("ring_buffer_mixed_operations", |b| {
         b.iter_batched(
-            || RingBuffer::<i32, 500>::new(0),
+            RingBuffer::<i32, 500>::new,
             |mut rb| {
                 // Simulate realistic card game usage patterns
                 for round in 0..50 {
@@ -187,7 +187,7 @@ fn bench_ring_buffer_memory_sizes(c: &mut Criterion) {
     // Test different buffer sizes to show scaling
     group.bench_function("size_10", |b| {
         b.iter_batched(
-            || RingBuffer::<u8, 10>::new(0),
+            RingBuffer::<u8, 10>::new,
             |mut rb| {
                 for i in 0..10 {
                     rb.push_back(i);
@@ -203,7 +203,7 @@ fn bench_ring_buffer_memory_sizes(c: &mut Criterion) {
 
     group.bench_function("size_52", |b| {
         b.iter_batched(
-            || RingBuffer::<u8, 52>::new(0),
+            RingBuffer::<u8, 52>::new,
             |mut rb| {
                 for i in 0..52 {
                     rb.push_back(i);
@@ -219,7 +219,7 @@ fn bench_ring_buffer_memory_sizes(c: &mut Criterion) {
 
     group.bench_function("size_1000", |b| {
         b.iter_batched(
-            || RingBuffer::<u8, 1000>::new(0),
+            RingBuffer::<u8, 1000>::new,
             |mut rb| {
                 for i in 0..100 {
                     // Only use part of the buffer
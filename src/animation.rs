@@ -0,0 +1,72 @@
+use std::io::{self, Write};
+use std::time::Duration;
+
+/// Pause between characters of a slow reveal
+pub const REVEAL_DELAY: Duration = Duration::from_millis(40);
+
+/// Source of delays for animations, so tests can run them instantly
+pub trait Clock {
+    fn sleep(&mut self, duration: Duration);
+}
+
+/// Clock backed by the real thread sleep
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn sleep(&mut self, duration: Duration) {
+        // Browsers can't block the main thread, so animations are instant there
+        #[cfg(not(target_arch = "wasm32"))]
+        std::thread::sleep(duration);
+        #[cfg(target_arch = "wasm32")]
+        let _ = duration;
+    }
+}
+
+/// Write `text` one character at a time with `delay` between each, then a newline
+pub fn slow_reveal<W: Write, C: Clock>(
+    out: &mut W,
+    clock: &mut C,
+    text: &str,
+    delay: Duration,
+) -> io::Result<()> {
+    let mut utf8 = [0; 4];
+    for ch in text.chars() {
+        out.write_all(ch.encode_utf8(&mut utf8).as_bytes())?;
+        out.flush()?;
+        clock.sleep(delay);
+    }
+    writeln!(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Records requested sleeps without waiting
+    #[derive(Default)]
+    struct FakeClock {
+        sleeps: usize,
+        total: Duration,
+    }
+
+    impl Clock for FakeClock {
+        fn sleep(&mut self, duration: Duration) {
+            self.sleeps += 1;
+            self.total += duration;
+        }
+    }
+
+    #[test]
+    fn test_slow_reveal_writes_full_text() {
+        let mut out = Vec::new();
+        let mut clock = FakeClock::default();
+        let text = "War cards - Player 1: ♥ King (13), Player 2: ♠ Two (2)";
+
+        slow_reveal(&mut out, &mut clock, text, Duration::ZERO).unwrap();
+
+        assert_eq!(String::from_utf8(out).unwrap(), format!("{}\n", text));
+        assert_eq!(clock.sleeps, text.chars().count());
+        assert_eq!(clock.total, Duration::ZERO);
+    }
+}
@@ -0,0 +1,317 @@
+use crate::game::{GameConfig, GameError, GameResult};
+use crate::simulation::{GameSummary, simulate_with};
+use std::fmt;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Aggregate results of many simulated games
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BatchSummary {
+    pub games: usize,
+    /// Games won (or led at the round limit) by player 1 and player 2
+    pub wins: [usize; 2],
+    /// Games drawn: cut off by the round limit with equal card counts, or
+    /// caught looping (see `GameConfig::detect_cycles`)
+    pub ties: usize,
+    /// Games cut off by the round limit
+    pub truncated: usize,
+    /// Totals saturate at `u64::MAX` rather than wrapping
+    pub total_rounds: u64,
+    pub total_wars: u64,
+}
+
+impl BatchSummary {
+    /// Fold one game's result into the totals
+    pub fn record(&mut self, summary: &GameSummary) {
+        self.games += 1;
+        match summary.winner {
+            Some(player) => self.wins[player - 1] += 1,
+            None => self.ties += 1,
+        }
+        if summary.truncated {
+            self.truncated += 1;
+        }
+        self.total_rounds = self.total_rounds.saturating_add(summary.rounds as u64);
+        self.total_wars = self.total_wars.saturating_add(summary.wars as u64);
+    }
+}
+
+impl fmt::Display for BatchSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} games: Player 1 won {}, Player 2 won {}, {} tied ({} cut off at the round limit)",
+            self.games, self.wins[0], self.wins[1], self.ties, self.truncated
+        )
+    }
+}
+
+/// How often each player wins across many simulated deals, as a Monte Carlo
+/// estimate of the odds of a shuffled game
+/// Every game lands in exactly one of `wins`, `draws` or `round_limit`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WinStats {
+    pub samples: usize,
+    /// Games player 1 and player 2 won outright, by taking every card or
+    /// reaching `GameConfig::target_score`
+    pub wins: [usize; 2],
+    /// Games cut off by the round limit with equal card counts, or caught
+    /// looping (see `GameConfig::detect_cycles`)
+    pub draws: usize,
+    /// Games cut off by the round limit with one player ahead
+    pub round_limit: usize,
+}
+
+impl WinStats {
+    /// Fold one game's result into the tallies
+    pub fn record(&mut self, summary: &GameSummary) {
+        self.samples += 1;
+        match (summary.truncated, summary.winner) {
+            (false, Some(player)) => self.wins[player - 1] += 1,
+            (_, None) => self.draws += 1,
+            (true, Some(_)) => self.round_limit += 1,
+        }
+    }
+
+    /// Estimated chance that `player` (1 or 2) wins outright
+    pub fn win_probability(&self, player: usize) -> f64 {
+        self.share(self.wins[player - 1])
+    }
+
+    /// Estimated chance of a draw
+    pub fn draw_probability(&self) -> f64 {
+        self.share(self.draws)
+    }
+
+    /// Estimated chance the round limit cuts the game off with a leader
+    pub fn round_limit_probability(&self) -> f64 {
+        self.share(self.round_limit)
+    }
+
+    fn share(&self, count: usize) -> f64 {
+        if self.samples == 0 {
+            0.0
+        } else {
+            count as f64 / self.samples as f64
+        }
+    }
+}
+
+impl fmt::Display for WinStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} games:", self.samples)?;
+        for player in 1..=2 {
+            writeln!(
+                f,
+                "{:<16} {:>6.2}%",
+                format!("Player {} wins", player),
+                self.win_probability(player) * 100.0
+            )?;
+        }
+        writeln!(
+            f,
+            "{:<16} {:>6.2}%",
+            "Draw",
+            self.draw_probability() * 100.0
+        )?;
+        write!(
+            f,
+            "{:<16} {:>6.2}%",
+            "Round limit",
+            self.round_limit_probability() * 100.0
+        )
+    }
+}
+
+/// Progress report passed to a batch callback
+#[derive(Debug, Clone, Copy)]
+pub struct Progress {
+    pub completed: usize,
+    pub elapsed: Duration,
+}
+
+/// Simulate one game per seed under `config` and aggregate the results
+pub fn simulate_many<I: IntoIterator<Item = u64>>(
+    seeds: I,
+    config: GameConfig,
+    max_rounds: usize,
+) -> BatchSummary {
+    let mut batch = BatchSummary::default();
+    for seed in seeds {
+        batch.record(&simulate_with(seed, config, max_rounds));
+    }
+    batch
+}
+
+/// Estimate each player's odds under `config` by simulating one game per seed
+pub fn estimate_wins<I: IntoIterator<Item = u64>>(
+    seeds: I,
+    config: GameConfig,
+    max_rounds: usize,
+) -> WinStats {
+    let mut stats = WinStats::default();
+    for seed in seeds {
+        stats.record(&simulate_with(seed, config, max_rounds));
+    }
+    stats
+}
+
+/// Like `simulate_many`, calling `on_progress` after every `every` completed games
+/// A final partial chunk does not trigger a call; `every` must be non-zero
+pub fn simulate_many_with_progress<I, F>(
+    seeds: I,
+    config: GameConfig,
+    max_rounds: usize,
+    every: usize,
+    mut on_progress: F,
+) -> BatchSummary
+where
+    I: IntoIterator<Item = u64>,
+    F: FnMut(Progress),
+{
+    assert!(every > 0, "progress interval must be non-zero");
+
+    let start = Instant::now();
+    let mut batch = BatchSummary::default();
+    for seed in seeds {
+        batch.record(&simulate_with(seed, config, max_rounds));
+        if batch.games % every == 0 {
+            on_progress(Progress {
+                completed: batch.games,
+                elapsed: start.elapsed(),
+            });
+        }
+    }
+    batch
+}
+
+/// Parse one u64 seed per line; blank lines are skipped
+pub fn parse_seeds(text: &str) -> GameResult<Vec<u64>> {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(number, line)| {
+            line.parse().map_err(|_| {
+                GameError::InvalidConfig(format!("line {}: '{}' is not a seed", number, line))
+            })
+        })
+        .collect()
+}
+
+/// Read a seed file in the format accepted by `parse_seeds`
+pub fn read_seeds(path: &Path) -> GameResult<Vec<u64>> {
+    parse_seeds(&std::fs::read_to_string(path)?)
+}
+
+/// Simulate each seed under `config`, writing one result line per seed, and
+/// return the totals
+pub fn report_seeds<W: Write>(
+    seeds: &[u64],
+    config: GameConfig,
+    max_rounds: usize,
+    out: &mut W,
+) -> io::Result<BatchSummary> {
+    let mut batch = BatchSummary::default();
+    for &seed in seeds {
+        let summary = simulate_with(seed, config, max_rounds);
+        writeln!(out, "seed {}: {}", seed, summary)?;
+        batch.record(&summary);
+    }
+    Ok(batch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::DEFAULT_MAX_ROUNDS;
+
+    #[test]
+    fn test_simulate_many_counts_every_game() {
+        let batch = simulate_many(0..20, GameConfig::default(), DEFAULT_MAX_ROUNDS);
+
+        assert_eq!(batch.games, 20);
+        assert_eq!(batch.wins[0] + batch.wins[1] + batch.ties, 20);
+        assert!(batch.total_rounds > 0);
+    }
+
+    #[test]
+    fn test_win_probabilities_sum_to_one() {
+        let stats = estimate_wins(0..50, GameConfig::default(), 200);
+        assert_eq!(stats.samples, 50);
+        assert_eq!(
+            stats.wins[0] + stats.wins[1] + stats.draws + stats.round_limit,
+            50
+        );
+
+        let total = stats.win_probability(1)
+            + stats.win_probability(2)
+            + stats.draw_probability()
+            + stats.round_limit_probability();
+        assert!((total - 1.0).abs() < 1e-9);
+
+        // The same seeds decide the same games as a batch does
+        let batch = simulate_many(0..50, GameConfig::default(), 200);
+        assert_eq!(stats.round_limit + stats.draws, batch.truncated);
+        assert_eq!(WinStats::default().win_probability(1), 0.0);
+    }
+
+    #[test]
+    fn test_rule_options_change_the_estimate() {
+        let config = GameConfig {
+            detect_cycles: true,
+            ..GameConfig::default()
+        };
+        // Seed 23 loops forever, so cycle detection ends it long before the
+        // round limit would
+        let plain = simulate_many([23], GameConfig::default(), DEFAULT_MAX_ROUNDS);
+        let detected = simulate_many([23], config, DEFAULT_MAX_ROUNDS);
+        assert_eq!((plain.truncated, detected.truncated), (1, 0));
+        assert!(detected.total_rounds < plain.total_rounds);
+    }
+
+    #[test]
+    fn test_progress_fires_every_k_games() {
+        let mut reports = Vec::new();
+        let batch =
+            simulate_many_with_progress(0..35, GameConfig::default(), 100, 10, |progress| {
+                reports.push(progress.completed);
+            });
+
+        assert_eq!(batch.games, 35);
+        assert_eq!(reports, vec![10, 20, 30]);
+        assert_eq!(batch, simulate_many(0..35, GameConfig::default(), 100));
+    }
+
+    #[test]
+    fn test_seed_file_gives_one_line_per_seed() {
+        let path = std::env::temp_dir().join(format!("war-rust-seeds-{}.txt", std::process::id()));
+        std::fs::write(&path, "1\n42\n\n  7  \n").unwrap();
+        let seeds = read_seeds(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(seeds.unwrap(), vec![1, 42, 7]);
+
+        let mut out = Vec::new();
+        let batch = report_seeds(&[1, 42, 7], GameConfig::default(), 100, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.lines().count(), 3);
+        assert!(out.lines().nth(1).unwrap().starts_with("seed 42: "));
+        assert_eq!(batch, simulate_many([1, 42, 7], GameConfig::default(), 100));
+    }
+
+    #[test]
+    fn test_invalid_seed_lines_are_reported() {
+        match parse_seeds("1\nforty-two\n3") {
+            Err(GameError::InvalidConfig(message)) => {
+                assert!(message.contains("line 2") && message.contains("forty-two"))
+            }
+            other => panic!("expected InvalidConfig, got {:?}", other),
+        }
+        assert!(parse_seeds("-1").is_err());
+        assert!(matches!(
+            read_seeds(Path::new("/nonexistent/seeds.txt")),
+            Err(GameError::IoError(_))
+        ));
+    }
+}
@@ -0,0 +1,180 @@
+use crate::cards::Card;
+
+/// Bitmask of all 52 card positions
+const FULL_MASK: u64 = (1 << 52) - 1;
+
+/// A set of cards packed into a single `u64`
+/// Each of the 52 cards owns one bit, so membership tests and set
+/// operations are a handful of bitwise instructions with no allocation
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[repr(transparent)]
+pub struct CardSet(u64);
+
+impl CardSet {
+    /// Create an empty set
+    pub fn new() -> Self {
+        CardSet(0)
+    }
+
+    /// Create a set holding every card of a standard deck
+    pub fn full() -> Self {
+        CardSet(FULL_MASK)
+    }
+
+    /// Add a card to the set
+    /// Returns true if the card was not already present
+    pub fn insert(&mut self, card: Card) -> bool {
+        let bit = 1 << card.index();
+        let added = self.0 & bit == 0;
+        self.0 |= bit;
+        added
+    }
+
+    /// Remove a card from the set
+    /// Returns true if the card was present
+    pub fn remove(&mut self, card: Card) -> bool {
+        let bit = 1 << card.index();
+        let removed = self.0 & bit != 0;
+        self.0 &= !bit;
+        removed
+    }
+
+    /// Returns true if the card is in the set
+    pub fn contains(&self, card: Card) -> bool {
+        self.0 & (1 << card.index()) != 0
+    }
+
+    /// Returns the number of cards in the set
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns true if the set holds no cards
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns true if the set holds all 52 cards
+    pub fn is_full(&self) -> bool {
+        self.0 == FULL_MASK
+    }
+
+    /// Cards present in either set
+    pub fn union(&self, other: &CardSet) -> CardSet {
+        CardSet(self.0 | other.0)
+    }
+
+    /// Cards present in both sets
+    pub fn intersection(&self, other: &CardSet) -> CardSet {
+        CardSet(self.0 & other.0)
+    }
+
+    /// Iterate over the cards in the set in index order
+    /// (by rank, then by suit within a rank)
+    pub fn iter(&self) -> CardSetIter {
+        CardSetIter { bits: self.0 }
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = Card>>(iter: I) -> Self {
+        let mut set = CardSet::new();
+        for card in iter {
+            set.insert(card);
+        }
+        set
+    }
+}
+
+/// Iterator over the cards in a `CardSet`
+pub struct CardSetIter {
+    bits: u64,
+}
+
+impl Iterator for CardSetIter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bits == 0 {
+            return None;
+        }
+
+        let index = self.bits.trailing_zeros() as usize;
+        self.bits &= self.bits - 1; // Clear the lowest set bit
+        Some(Card::from_index(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.bits.count_ones() as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for CardSetIter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::{Deck, Rank, Suit};
+
+    #[test]
+    fn test_insert_contains_remove() {
+        let mut set = CardSet::new();
+        let ace = Card::new(Suit::Spades, Rank::Ace);
+        let two = Card::new(Suit::Hearts, Rank::Two);
+
+        assert!(set.is_empty());
+        assert!(set.insert(ace));
+        assert!(!set.insert(ace)); // Already present
+        assert!(set.contains(ace));
+        assert!(!set.contains(two));
+        assert_eq!(set.len(), 1);
+
+        assert!(set.remove(ace));
+        assert!(!set.remove(ace));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_iter() {
+        let king = Card::new(Suit::Clubs, Rank::King);
+        let two = Card::new(Suit::Diamonds, Rank::Two);
+        let ten = Card::new(Suit::Hearts, Rank::Ten);
+        let set: CardSet = [king, two, ten].into_iter().collect();
+
+        assert_eq!(set.iter().len(), 3);
+        // Iteration is ordered by rank
+        let cards: Vec<Card> = set.iter().collect();
+        assert_eq!(cards, vec![two, ten, king]);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = Card::new(Suit::Hearts, Rank::Ace);
+        let b = Card::new(Suit::Spades, Rank::Ace);
+        let c = Card::new(Suit::Clubs, Rank::Ace);
+        let left: CardSet = [a, b].into_iter().collect();
+        let right: CardSet = [b, c].into_iter().collect();
+
+        let union = left.union(&right);
+        assert_eq!(union.len(), 3);
+        assert!(union.contains(a) && union.contains(b) && union.contains(c));
+
+        let intersection = left.intersection(&right);
+        assert_eq!(intersection.len(), 1);
+        assert!(intersection.contains(b));
+    }
+
+    #[test]
+    fn test_full_deck_fills_all_bits() {
+        let (mut player1, mut player2) = Deck::new().split();
+        let mut set = CardSet::new();
+        while let Some(card) = player1.draw_card().or_else(|| player2.draw_card()) {
+            assert!(set.insert(card));
+        }
+
+        assert_eq!(set.len(), 52);
+        assert!(set.is_full());
+        assert_eq!(set, CardSet::full());
+    }
+}
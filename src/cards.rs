@@ -1,9 +1,18 @@
+use crate::card_set::CardSet;
+use crate::game::{GameError, GameResult};
 use crate::ring_buffer::RingBuffer;
+#[cfg(not(target_arch = "wasm32"))]
+use rand::rng;
 use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::{SeedableRng, rng};
+use rand::{Rng, SeedableRng};
+use std::fmt;
+use std::hash::Hasher;
+use std::str::FromStr;
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+/// Suits order as declared (Hearts < Spades < Clubs < Diamonds), which is
+/// only for sorting and grouping: suits never break ties in play
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(u8)]
 pub enum Suit {
     Hearts = 0,
@@ -28,16 +37,157 @@ pub enum Rank {
     Queen = 12,
     King = 13,
     Ace = 14,
+    /// Only in decks built by `Deck::new_with_jokers`; beats every other rank
+    Joker = 15,
+}
+
+/// Every suit as (suit, letter, symbol, name), in discriminant order
+/// The single source of truth for displaying and parsing suits
+const SUITS: [(Suit, &str, &str, &str); 4] = [
+    (Suit::Hearts, "H", "♥", "Hearts"),
+    (Suit::Spades, "S", "♠", "Spades"),
+    (Suit::Clubs, "C", "♣", "Clubs"),
+    (Suit::Diamonds, "D", "♦", "Diamonds"),
+];
+
+/// Every rank as (rank, symbol, name), in ascending order
+/// The single source of truth for displaying and parsing ranks
+const RANKS: [(Rank, &str, &str); 14] = [
+    (Rank::Two, "2", "Two"),
+    (Rank::Three, "3", "Three"),
+    (Rank::Four, "4", "Four"),
+    (Rank::Five, "5", "Five"),
+    (Rank::Six, "6", "Six"),
+    (Rank::Seven, "7", "Seven"),
+    (Rank::Eight, "8", "Eight"),
+    (Rank::Nine, "9", "Nine"),
+    (Rank::Ten, "10", "Ten"),
+    (Rank::Jack, "J", "Jack"),
+    (Rank::Queen, "Q", "Queen"),
+    (Rank::King, "K", "King"),
+    (Rank::Ace, "A", "Ace"),
+    (Rank::Joker, "JK", "Joker"),
+];
+
+/// Most Jokers a deck can hold: any two distinct Jokers, whatever their suit
+/// bits, though `Deck::new_with_jokers` always adds a red one and a black one
+pub const MAX_JOKERS: usize = 2;
+
+/// Cards in the largest deck: the 52 standard cards plus every Joker
+pub const MAX_DECK_SIZE: usize = 52 + MAX_JOKERS;
+
+impl Suit {
+    /// All four suits, in discriminant order
+    pub fn all() -> impl Iterator<Item = Suit> {
+        SUITS.iter().map(|&(suit, ..)| suit)
+    }
+
+    /// Single-letter abbreviation, e.g. "H"
+    pub fn letter(self) -> &'static str {
+        SUITS[self as usize].1
+    }
+
+    /// Unicode symbol, e.g. "♥"
+    pub fn symbol(self) -> &'static str {
+        SUITS[self as usize].2
+    }
+
+    pub fn name(self) -> &'static str {
+        SUITS[self as usize].3
+    }
+}
+
+impl fmt::Display for Suit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Accepts the letter, symbol or name, ignoring case
+impl FromStr for Suit {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SUITS
+            .iter()
+            .find(|&&(_, letter, symbol, name)| {
+                s.eq_ignore_ascii_case(letter) || s == symbol || s.eq_ignore_ascii_case(name)
+            })
+            .map(|&(suit, ..)| suit)
+            .ok_or_else(|| ParseCardError::UnknownSuit(s.to_string()))
+    }
+}
+
+impl Rank {
+    /// All thirteen standard ranks, lowest first (no Joker)
+    pub fn all() -> impl Iterator<Item = Rank> {
+        RANKS[..13].iter().map(|&(rank, ..)| rank)
+    }
+
+    /// The rank with numeric value `value` (2-15)
+    pub fn from_value(value: u8) -> Option<Rank> {
+        let index = value.checked_sub(2)?;
+        RANKS.get(index as usize).map(|&(rank, ..)| rank)
+    }
+
+    /// Short symbol, e.g. "10" or "K"
+    pub fn symbol(self) -> &'static str {
+        RANKS[self as usize - 2].1
+    }
+
+    pub fn name(self) -> &'static str {
+        RANKS[self as usize - 2].2
+    }
+}
+
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Accepts the symbol or name, ignoring case
+impl FromStr for Rank {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        RANKS
+            .iter()
+            .find(|&&(_, symbol, name)| {
+                s.eq_ignore_ascii_case(symbol) || s.eq_ignore_ascii_case(name)
+            })
+            .map(|&(rank, ..)| rank)
+            .ok_or_else(|| ParseCardError::UnknownRank(s.to_string()))
+    }
 }
 
 /// Ultra-compact card representation: 1 byte total
 /// Bits 0-1: Suit (4 suits = 2 bits)
-/// Bits 2-7: Rank (13 ranks, values 2-14 = 6 bits)
-#[derive(Debug, Copy, Clone, PartialEq)]
+/// Bits 2-7: Rank (13 ranks and the Joker, values 2-15 = 6 bits)
+/// A Joker's suit bits only tell it apart from the other Joker
+///
+/// Every `Card` holds a valid packed byte, so `suit()` and `rank()` never panic
+/// Any constructor that accepts a raw byte must reject it unless
+/// `is_valid_byte` holds
+///
+/// Cards order by rank, then by suit in declaration order (Hearts, Spades,
+/// Clubs, Diamonds) so that `Ord` agrees with `Eq`. The suit tie-break is
+/// never a game rule: compare `rank()` or `value()` when ties matter
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct Card(u8);
 
 impl Card {
+    /// True if `byte` packs a real card: any suit bits, with rank bits in 2..=15
+    pub(crate) const fn is_valid_byte(byte: u8) -> bool {
+        matches!(byte >> 2, 2..=15)
+    }
+
+    /// All 52 standard cards, suit by suit, each suit from Two to Ace
+    pub fn all() -> impl Iterator<Item = Card> {
+        Suit::all().flat_map(|suit| Rank::all().map(move |rank| Card::new(suit, rank)))
+    }
+
     /// Create a new card from suit and rank
     pub fn new(suit: Suit, rank: Rank) -> Self {
         let suit_bits = (suit as u8) & 0b11; // 2 bits for suit
@@ -45,6 +195,21 @@ impl Card {
         Card((rank_bits << 2) | suit_bits)
     }
 
+    /// The packed byte, for compact serialization (see `from_u8`)
+    pub const fn as_u8(&self) -> u8 {
+        self.0
+    }
+
+    /// Rebuild a card from `as_u8` output, or None if the byte doesn't
+    /// pack a real card (rank field outside 2..=15)
+    pub const fn from_u8(byte: u8) -> Option<Card> {
+        if Card::is_valid_byte(byte) {
+            Some(Card(byte))
+        } else {
+            None
+        }
+    }
+
     /// Extract the suit from the packed representation
     pub fn suit(&self) -> Suit {
         match self.0 & 0b11 {
@@ -58,94 +223,409 @@ impl Card {
 
     /// Extract the rank from the packed representation
     pub fn rank(&self) -> Rank {
-        let rank_value = (self.0 >> 2) & 0b111111;
-        match rank_value {
-            2 => Rank::Two,
-            3 => Rank::Three,
-            4 => Rank::Four,
-            5 => Rank::Five,
-            6 => Rank::Six,
-            7 => Rank::Seven,
-            8 => Rank::Eight,
-            9 => Rank::Nine,
-            10 => Rank::Ten,
-            11 => Rank::Jack,
-            12 => Rank::Queen,
-            13 => Rank::King,
-            14 => Rank::Ace,
-            _ => unreachable!(), // Only valid rank values
+        match Rank::from_value(self.value()) {
+            Some(rank) => rank,
+            None => unreachable!(), // Only valid rank values
         }
     }
 
+    /// Dense index used as the bit position in a `CardSet`: 0..52 for the
+    /// standard cards, then 52..56 for Jokers
+    /// Ranks start at 2, so the packed byte is offset by 2 << 2
+    pub(crate) fn index(&self) -> usize {
+        (self.0 - 8) as usize
+    }
+
+    /// Inverse of `index`; the caller guarantees `index < 56`
+    pub(crate) fn from_index(index: usize) -> Self {
+        debug_assert!(index < 56);
+        let byte = index as u8 + 8;
+        debug_assert!(Card::is_valid_byte(byte));
+        Card(byte)
+    }
+
     /// Get the numeric value of the card for comparison
     pub fn value(&self) -> u8 {
         (self.0 >> 2) & 0b111111
     }
 
+    /// Get the card's value under a house-rule table
+    pub fn value_with(&self, table: &ValueTable) -> u8 {
+        table.0[self.value() as usize]
+    }
+
+    /// Get the single-glyph Unicode playing card (e.g. 🂡 for the Ace of Spades)
+    pub fn to_emoji(&self) -> char {
+        // Each suit has a 16-slot row; slot 0 is the card back
+        let suit_base: u32 = match self.suit() {
+            Suit::Spades => 0x1F0A0,
+            Suit::Hearts => 0x1F0B0,
+            Suit::Diamonds => 0x1F0C0,
+            Suit::Clubs => 0x1F0D0,
+        };
+        // Jokers have no suit row of their own, only a red and a black glyph
+        if self.rank() == Rank::Joker {
+            return match self.suit() {
+                Suit::Hearts | Suit::Diamonds => '\u{1F0BF}',
+                Suit::Spades | Suit::Clubs => '\u{1F0CF}',
+            };
+        }
+        // Aces sit in slot 1, and the row has a Knight between Jack and Queen
+        let slot = match self.rank() {
+            Rank::Ace => 1,
+            Rank::Queen | Rank::King => self.value() as u32 + 1,
+            _ => self.value() as u32,
+        };
+        char::from_u32(suit_base + slot).expect("playing card block is fully assigned")
+    }
+
     /// Get the suit symbol for display
     pub fn suit_symbol(&self) -> &'static str {
-        match self.suit() {
-            Suit::Hearts => "♥",
-            Suit::Spades => "♠",
-            Suit::Clubs => "♣",
-            Suit::Diamonds => "♦",
+        self.suit().symbol()
+    }
+
+    /// Compact code: rank symbol then suit letter, e.g. "AH" or "10S"
+    pub fn code(&self) -> String {
+        format!("{}{}", self.rank().symbol(), self.suit().letter())
+    }
+}
+
+/// Why a string couldn't be parsed as a `Card`, `Rank` or `Suit`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseCardError {
+    /// Not 2 or 3 characters, so it can't be a rank symbol plus a suit
+    BadLength(usize),
+    UnknownRank(String),
+    UnknownSuit(String),
+}
+
+impl fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseCardError::BadLength(length) => write!(
+                f,
+                "expected a rank and suit like \"AH\" or \"10S\", got {} characters",
+                length
+            ),
+            ParseCardError::UnknownRank(rank) => write!(f, "unknown rank '{}'", rank),
+            ParseCardError::UnknownSuit(suit) => write!(f, "unknown suit '{}'", suit),
+        }
+    }
+}
+
+impl std::error::Error for ParseCardError {}
+
+impl From<ParseCardError> for GameError {
+    fn from(error: ParseCardError) -> Self {
+        GameError::InvalidCard(error.to_string())
+    }
+}
+
+/// Inverse of `Card::code`. Accepts a rank symbol then a suit letter or
+/// symbol, ignoring case: "AH", "10c" and the `Display` form "K♠" all parse
+impl FromStr for Card {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let length = s.chars().count();
+        let Some((split, _)) = s
+            .char_indices()
+            .last()
+            .filter(|_| (2..=3).contains(&length))
+        else {
+            return Err(ParseCardError::BadLength(length));
+        };
+        let (rank, suit) = s.split_at(split);
+
+        // No rank or suit name fits in three characters, so only symbols
+        // and letters get this far
+        Ok(Card::new(suit.parse()?, rank.parse()?))
+    }
+}
+
+/// Rank symbol then suit symbol, e.g. "A♥" or "10♠"
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.rank().symbol(), self.suit().symbol())
+    }
+}
+
+/// Return the higher-ranked of two cards
+/// Suits never break ties: when the ranks are equal, `a` is returned
+pub fn max_card(a: Card, b: Card) -> Card {
+    if b.rank().cmp(&a.rank()).is_gt() {
+        b
+    } else {
+        a
+    }
+}
+
+/// Return the lower-ranked of two cards
+/// Suits never break ties: when the ranks are equal, `a` is returned
+pub fn min_card(a: Card, b: Card) -> Card {
+    if b.rank().cmp(&a.rank()).is_lt() {
+        b
+    } else {
+        a
+    }
+}
+
+/// A card on the table; face-down cards (war burns) render as "??"
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PlayedCard {
+    pub card: Card,
+    pub face_up: bool,
+}
+
+impl fmt::Display for PlayedCard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.face_up {
+            write!(f, "{}", self.card)
+        } else {
+            write!(f, "??")
+        }
+    }
+}
+
+/// Maps each rank's numeric value (2-15) to the value it plays at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValueTable(pub [u8; 16]);
+
+impl ValueTable {
+    /// Every rank plays at its face value
+    pub const fn identity() -> Self {
+        let mut values = [0; 16];
+        let mut i = 0;
+        while i < values.len() {
+            values[i] = i as u8;
+            i += 1;
         }
+        ValueTable(values)
+    }
+
+    /// Aces play lowest, at 1, below the Two
+    pub const fn ace_low() -> Self {
+        Self::identity().with(Rank::Ace, 1)
+    }
+
+    /// Return a copy with `rank` playing at `value`
+    pub const fn with(mut self, rank: Rank, value: u8) -> Self {
+        self.0[rank as usize] = value;
+        self
+    }
+}
+
+impl Default for ValueTable {
+    fn default() -> Self {
+        Self::identity()
     }
 }
 
 #[derive(Debug)]
 pub struct Deck {
-    cards: [Card; 52],
+    /// The deck occupies the first `size` slots
+    cards: [Card; MAX_DECK_SIZE],
+    /// 52, plus one per Joker
+    size: usize,
+    /// Number of cards already drawn from the top
+    dealt: usize,
 }
 
 impl Deck {
     pub fn new() -> Self {
-        let suits = [Suit::Hearts, Suit::Spades, Suit::Clubs, Suit::Diamonds];
-        let ranks = [
-            Rank::Two,
-            Rank::Three,
-            Rank::Four,
-            Rank::Five,
-            Rank::Six,
-            Rank::Seven,
-            Rank::Eight,
-            Rank::Nine,
-            Rank::Ten,
-            Rank::Jack,
-            Rank::Queen,
-            Rank::King,
-            Rank::Ace,
-        ];
+        Self::new_with_jokers(0)
+    }
 
-        let mut cards = [Card::new(Suit::Hearts, Rank::Two); 52];
-        let mut index = 0;
+    /// The 52 standard cards followed by `count` Jokers, red first
+    /// Panics if `count` is more than `MAX_JOKERS`
+    pub fn new_with_jokers(count: usize) -> Self {
+        assert!(
+            count <= MAX_JOKERS,
+            "a deck holds at most {} Jokers, got {}",
+            MAX_JOKERS,
+            count
+        );
+        let jokers = [Suit::Hearts, Suit::Spades].map(|suit| Card::new(suit, Rank::Joker));
+        let mut cards = [Card::new(Suit::Hearts, Rank::Two); MAX_DECK_SIZE];
+        let all = Card::all().chain(jokers.into_iter().take(count));
+        let mut size = 0;
+        for (slot, card) in cards.iter_mut().zip(all) {
+            *slot = card;
+            size += 1;
+        }
 
-        for &suit in &suits {
-            for &rank in &ranks {
-                cards[index] = Card::new(suit, rank);
-                index += 1;
-            }
+        Deck {
+            cards,
+            size,
+            dealt: 0,
         }
+    }
 
-        Deck { cards }
+    /// The cards not yet drawn, top first
+    fn undealt(&self) -> &[Card] {
+        &self.cards[self.dealt..self.size]
     }
 
+    /// Shuffle using OS entropy (unavailable on wasm32; use `shuffle_with_seed`)
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn shuffle(&mut self) {
-        let mut rng = rng();
-        self.cards.shuffle(&mut rng);
+        self.shuffle_with_rng(&mut rng());
     }
 
+    /// Shuffle with `StdRng` seeded from `seed`
     pub fn shuffle_with_seed(&mut self, seed: u64) {
-        let mut rng = StdRng::seed_from_u64(seed);
-        self.cards.shuffle(&mut rng);
+        self.shuffle_with_rng(&mut StdRng::seed_from_u64(seed));
+    }
+
+    /// Shuffle the undrawn cards with any generator, e.g. a portable
+    /// `ChaCha8Rng` or a scripted mock in tests
+    pub fn shuffle_with_rng<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let before = self.card_set();
+        self.cards[self.dealt..self.size].shuffle(rng);
+        self.debug_check_permutation(before);
+    }
+
+    /// The distinct cards in the deck, drawn or not
+    fn card_set(&self) -> CardSet {
+        self.cards[..self.size].iter().copied().collect()
+    }
+
+    /// True if the deck holds each of the 52 cards exactly once, and no Jokers
+    pub fn is_standard(&self) -> bool {
+        // 52 slots covering 52 distinct cards leaves no room for duplicates
+        self.size == 52 && self.card_set().is_full()
+    }
+
+    /// True if the deck holds each of the 52 standard cards exactly once,
+    /// plus at most `MAX_JOKERS` distinct Jokers: worth checking on a deck
+    /// built with `from_cards_unchecked` or from untrusted input
+    pub fn is_valid(&self) -> bool {
+        let set = self.card_set();
+        // As many distinct cards as slots means none is repeated
+        set.len() == self.size
+            && self.size <= 52 + MAX_JOKERS
+            && set.intersection(&CardSet::full()).is_full()
+    }
+
+    /// Debug builds panic if a shuffle lost or duplicated a card
+    fn debug_check_permutation(&self, before: CardSet) {
+        debug_assert_eq!(self.card_set(), before, "shuffle is not a permutation");
+    }
+
+    /// Build a deck from exactly 52 standard cards and up to `MAX_JOKERS`
+    /// Jokers, in iteration order
+    /// Fails if the count is wrong, or if any card appears twice
+    pub fn try_from_iter<I: IntoIterator<Item = Card>>(iter: I) -> GameResult<Deck> {
+        let mut cards = [Card::new(Suit::Hearts, Rank::Two); MAX_DECK_SIZE];
+        let mut count = 0;
+        let mut jokers = 0;
+        let mut seen = CardSet::new();
+        let mut repeated = None;
+
+        for card in iter {
+            if let Some(slot) = cards.get_mut(count) {
+                *slot = card;
+            }
+            count += 1;
+            if card.rank() == Rank::Joker {
+                jokers += 1;
+            }
+            if !seen.insert(card) {
+                repeated.get_or_insert(card);
+            }
+        }
+
+        if count - jokers != 52 || jokers > MAX_JOKERS {
+            return Err(GameError::InvalidDeckSize(count));
+        }
+        if let Some(card) = repeated {
+            return Err(GameError::InvalidCard(format!("{} appears twice", card)));
+        }
+        Ok(Deck {
+            cards,
+            size: count,
+            dealt: 0,
+        })
+    }
+
+    /// Stack a standard deck in exactly this order, top first, e.g. to set up
+    /// a war in a test
+    /// Fails if a card appears twice or is a Joker
+    pub fn from_cards(cards: [Card; 52]) -> GameResult<Deck> {
+        let mut seen = CardSet::new();
+        for card in cards {
+            if card.rank() == Rank::Joker {
+                return Err(GameError::InvalidCard(format!(
+                    "{} is not one of the 52 standard cards",
+                    card
+                )));
+            }
+            if !seen.insert(card) {
+                return Err(GameError::InvalidCard(format!("{} appears twice", card)));
+            }
+        }
+        Ok(Deck::from_cards_unchecked(cards))
+    }
+
+    /// Like `from_cards`, but takes the cards as they are, repeats and Jokers
+    /// included
+    pub fn from_cards_unchecked(cards: [Card; 52]) -> Deck {
+        let mut slots = [Card::new(Suit::Hearts, Rank::Two); MAX_DECK_SIZE];
+        slots[..52].copy_from_slice(&cards);
+        Deck {
+            cards: slots,
+            size: 52,
+            dealt: 0,
+        }
+    }
+
+    /// The cards not yet drawn, top first
+    pub fn iter(&self) -> impl Iterator<Item = Card> + '_ {
+        self.undealt().iter().copied()
+    }
+
+    /// The undrawn cards as space-separated codes, top first (see `Card::code`)
+    pub fn to_codes(&self) -> String {
+        self.iter()
+            .map(|card| card.code())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Rebuild a full deck from the output of `to_codes`
+    pub fn from_codes(codes: &str) -> GameResult<Deck> {
+        let cards = codes
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<Card>, _>>()?;
+        Deck::try_from_iter(cards)
+    }
+
+    /// Number of cards not yet drawn
+    pub fn remaining(&self) -> usize {
+        self.size - self.dealt
+    }
+
+    /// Take the top card, or None once the whole deck has been drawn
+    pub fn draw(&mut self) -> Option<Card> {
+        let card = *self.undealt().first()?;
+        self.dealt += 1;
+        Some(card)
     }
 
+    /// Deal the remaining cards alternately into two hands, player 1 first
     pub fn split(self) -> (PlayerHand, PlayerHand) {
+        self.split_with_offset(0)
+    }
+
+    /// Deal alternately, starting with player 1 when `offset` is even and
+    /// player 2 when it is odd
+    pub fn split_with_offset(self, offset: usize) -> (PlayerHand, PlayerHand) {
         let mut player1 = PlayerHand::new();
         let mut player2 = PlayerHand::new();
 
-        for (i, card) in self.cards.iter().enumerate() {
-            if i % 2 == 0 {
+        for (i, card) in self.undealt().iter().enumerate() {
+            if (i + offset).is_multiple_of(2) {
                 player1.add_card(*card);
             } else {
                 player2.add_card(*card);
@@ -154,18 +634,91 @@ impl Deck {
 
         (player1, player2)
     }
+
+    /// Deal the remaining cards round-robin into `players` hands, player 1
+    /// first; when `players` doesn't divide the deck, the first hands dealt
+    /// get one card more
+    /// Panics if `players` is 0
+    pub fn deal(self, players: usize) -> Vec<PlayerHand> {
+        self.deal_with_offset(players, 0)
+    }
+
+    /// Like `deal`, with the first card going to hand `offset % players`
+    pub fn deal_with_offset(self, players: usize, offset: usize) -> Vec<PlayerHand> {
+        let mut hands: Vec<PlayerHand> = (0..players).map(|_| PlayerHand::new()).collect();
+        for (i, card) in self.undealt().iter().enumerate() {
+            hands[(i + offset) % players].add_card(*card);
+        }
+        hands
+    }
 }
 
-/// A player's hand using a ring buffer for efficient card management
-#[derive(Debug)]
-pub struct PlayerHand {
-    cards: RingBuffer<Card, 52>,
+impl Default for Deck {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Draws from the top (index 0, the first card `split` deals), so
+/// `deck.take(10)` yields the next ten cards `draw` would return
+impl Iterator for Deck {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Card> {
+        self.draw()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining(), Some(self.remaining()))
+    }
+}
+
+impl ExactSizeIterator for Deck {}
+
+/// Collects exactly 52 cards, plus any Jokers; panics on any other count or
+/// on a repeated card (see `Deck::try_from_iter`)
+impl FromIterator<Card> for Deck {
+    fn from_iter<I: IntoIterator<Item = Card>>(iter: I) -> Self {
+        match Deck::try_from_iter(iter) {
+            Ok(deck) => deck,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+/// The order in which a won pot goes under the winner's hand, and therefore
+/// the order its cards come back up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PotOrder {
+    /// Cards go under in the order they hit the table, alternating players
+    #[default]
+    AsPlayed,
+    /// The winner's own cards go under first, then the loser's
+    WinnerFirst,
+    /// The loser's cards go under first, then the winner's own
+    LoserFirst,
+}
+
+/// A player's face-down pile, kept in a ring buffer for efficient card
+/// management; holds up to `CAP` cards (one deck, Jokers included, by
+/// default; use a larger capacity for multi-deck games)
+#[derive(Debug, Clone)]
+pub struct PlayerHand<const CAP: usize = MAX_DECK_SIZE> {
+    cards: RingBuffer<Card, CAP>,
 }
 
 impl PlayerHand {
+    /// An empty hand sized for one deck, Jokers included
     pub fn new() -> Self {
+        Self::empty()
+    }
+}
+
+impl<const CAP: usize> PlayerHand<CAP> {
+    /// An empty hand of any capacity, e.g. `PlayerHand::<104>::empty()`
+    pub fn empty() -> Self {
         Self {
-            cards: RingBuffer::new(Card::new(Suit::Hearts, Rank::Two)),
+            cards: RingBuffer::new(),
         }
     }
 
@@ -182,17 +735,757 @@ impl PlayerHand {
         self.cards.pop_back()
     }
 
+    /// The card `draw_card` would return next, without drawing it
+    pub fn peek_top(&self) -> Option<Card> {
+        self.cards.back()
+    }
+
+    /// The card at the bottom of the hand, drawn last unless more are added
+    pub fn peek_bottom(&self) -> Option<Card> {
+        self.cards.front()
+    }
+
+    /// The cards in the hand, bottom first: the reverse of the order
+    /// `draw_card` would take them
+    pub fn iter(&self) -> impl Iterator<Item = Card> + '_ {
+        self.cards.iter()
+    }
+
+    /// True if the hand holds `card`
+    pub fn contains(&self, card: Card) -> bool {
+        self.cards.iter().any(|held| held == card)
+    }
+
+    /// How many copies of `card` the hand holds (more than one only in multi-deck games)
+    pub fn count_of(&self, card: Card) -> usize {
+        self.cards.iter().filter(|&held| held == card).count()
+    }
+
+    /// Feed the hand's cards into `state`, in order, for fingerprinting
+    /// a game position
+    pub fn hash_cards<H: Hasher>(&self, state: &mut H) {
+        // Fixed width, so a fingerprint is the same on 32-bit targets
+        state.write_u32(self.len() as u32);
+        for card in &self.cards {
+            state.write_u8(card.as_u8());
+        }
+    }
+
     /// Add a single card to the back of the hand
     pub fn add_card(&mut self, card: Card) {
         self.cards.push_back(card);
     }
 
-    /// Transfer all cards from a battle buffer directly to the front of this hand
+    /// Count how many cards of each rank the hand holds, indexed by rank value
+    /// (2-14, and 15 for Jokers); indices 0 and 1 are always zero
+    pub fn rank_histogram(&self) -> [usize; 16] {
+        let mut histogram = [0; 16];
+        for card in &self.cards {
+            histogram[card.value() as usize] += 1;
+        }
+        histogram
+    }
+
+    /// Put a won pot under this hand in the order it was played
+    /// (`PotOrder::AsPlayed`): once the cards already held are drawn, the pot's
+    /// cards come back up first played first. Listed bottom first, as `iter`
+    /// does, the pot therefore reads back to front
     /// This avoids creating any temporary Vec allocations
-    pub fn take_battle_cards(&mut self, battle_buffer: &RingBuffer<Card, 52>) {
-        // Add all cards from the battle buffer to the front of this hand
-        for card in battle_buffer.iter() {
+    pub fn take_battle_cards<const B: usize>(&mut self, battle_buffer: &RingBuffer<Card, B>) {
+        // Each card goes under the one before it, so the last played ends up
+        // at the very bottom
+        for card in battle_buffer {
             self.cards.push_front(card);
         }
     }
+
+    /// Like `take_battle_cards`, but in a random order drawn from `rng`, the
+    /// way real players gather a pot without memorizing it
+    /// Fixed orders can lock a game into a loop; shuffling breaks it up
+    pub fn take_battle_cards_shuffled<R: Rng + ?Sized, const B: usize>(
+        &mut self,
+        battle_buffer: &RingBuffer<Card, B>,
+        rng: &mut R,
+    ) {
+        // Picking the cards off a copy one at a time shuffles without a Vec
+        let mut pot = battle_buffer.clone();
+        while !pot.is_empty() {
+            if let Some(card) = pot.remove(rng.random_range(0..pot.len())) {
+                self.cards.push_front(card);
+            }
+        }
+    }
+
+    /// Like `take_battle_cards`, but sequencing the pot by `order`
+    /// The buffer must hold cards as played, player 1's first in each pair
+    pub fn take_battle_cards_ordered<const B: usize>(
+        &mut self,
+        battle_buffer: &RingBuffer<Card, B>,
+        order: PotOrder,
+        winner: usize,
+    ) {
+        // Player 1's cards sit at even positions, player 2's at odd ones
+        let winner_offset = usize::from(winner == 2);
+        let first_offset = match order {
+            PotOrder::AsPlayed => return self.take_battle_cards(battle_buffer),
+            PotOrder::WinnerFirst => winner_offset,
+            PotOrder::LoserFirst => 1 - winner_offset,
+        };
+
+        for offset in [first_offset, 1 - first_offset] {
+            for card in battle_buffer.iter().skip(offset).step_by(2) {
+                self.cards.push_front(card);
+            }
+        }
+    }
+}
+
+impl<const CAP: usize> Default for PlayerHand<CAP> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_and_min_card() {
+        let king = Card::new(Suit::Hearts, Rank::King);
+        let four = Card::new(Suit::Clubs, Rank::Four);
+
+        assert_eq!(max_card(king, four), king);
+        assert_eq!(max_card(four, king), king);
+        assert_eq!(min_card(king, four), four);
+        assert_eq!(min_card(four, king), four);
+    }
+
+    #[test]
+    fn test_max_and_min_card_tie_returns_first() {
+        let ace_hearts = Card::new(Suit::Hearts, Rank::Ace);
+        let ace_spades = Card::new(Suit::Spades, Rank::Ace);
+
+        assert_eq!(max_card(ace_hearts, ace_spades), ace_hearts);
+        assert_eq!(max_card(ace_spades, ace_hearts), ace_spades);
+        assert_eq!(min_card(ace_hearts, ace_spades), ace_hearts);
+        assert_eq!(min_card(ace_spades, ace_hearts), ace_spades);
+    }
+
+    #[test]
+    fn test_to_emoji() {
+        assert_eq!(Card::new(Suit::Spades, Rank::Ace).to_emoji(), '\u{1F0A1}');
+        assert_eq!(Card::new(Suit::Spades, Rank::Ten).to_emoji(), '\u{1F0AA}');
+        assert_eq!(Card::new(Suit::Hearts, Rank::Two).to_emoji(), '\u{1F0B2}');
+        assert_eq!(Card::new(Suit::Hearts, Rank::Jack).to_emoji(), '\u{1F0BB}');
+        // Queen skips over the Knight at U+1F0CC
+        assert_eq!(
+            Card::new(Suit::Diamonds, Rank::Queen).to_emoji(),
+            '\u{1F0CD}'
+        );
+        assert_eq!(Card::new(Suit::Clubs, Rank::King).to_emoji(), '\u{1F0DE}');
+    }
+
+    #[test]
+    fn test_rank_histogram() {
+        let mut hand = PlayerHand::new();
+        hand.add_card(Card::new(Suit::Hearts, Rank::Ace));
+        hand.add_card(Card::new(Suit::Spades, Rank::Ace));
+        hand.add_card(Card::new(Suit::Clubs, Rank::Ace));
+        hand.add_card(Card::new(Suit::Hearts, Rank::Two));
+        hand.add_card(Card::new(Suit::Diamonds, Rank::Ten));
+        hand.add_card(Card::new(Suit::Spades, Rank::Joker));
+
+        let histogram = hand.rank_histogram();
+
+        assert_eq!(histogram.iter().sum::<usize>(), hand.len());
+        assert_eq!(histogram[Rank::Joker as usize], 1);
+        assert_eq!(histogram[Rank::Ace as usize], 3);
+        assert_eq!(histogram[Rank::Two as usize], 1);
+        assert_eq!(histogram[Rank::Ten as usize], 1);
+        assert_eq!(histogram[Rank::King as usize], 0);
+        assert_eq!(histogram[0] + histogram[1], 0);
+    }
+
+    #[test]
+    fn test_draw_empties_the_deck() {
+        let mut deck = Deck::new();
+        let mut drawn = CardSet::new();
+
+        for remaining in (0..52).rev() {
+            drawn.insert(deck.draw().unwrap());
+            assert_eq!(deck.remaining(), remaining);
+        }
+
+        assert!(drawn.is_full());
+        assert_eq!(deck.draw(), None);
+    }
+
+    #[test]
+    fn test_split_deals_only_undrawn_cards() {
+        let mut deck = Deck::new();
+        let top = deck.draw().unwrap();
+        deck.draw();
+
+        let (player1, player2) = deck.split();
+        assert_eq!(player1.len() + player2.len(), 50);
+        let rank = top.rank() as usize;
+        assert_eq!(
+            player1.rank_histogram()[rank] + player2.rank_histogram()[rank],
+            3
+        );
+    }
+
+    #[test]
+    fn test_deck_from_iterator_product() {
+        // Suits in a different order than `Deck::new`
+        let suits = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades];
+        let deck: Deck = suits
+            .iter()
+            .flat_map(|&suit| Rank::all().map(move |rank| Card::new(suit, rank)))
+            .collect();
+
+        assert!(deck.is_standard());
+        assert_eq!(deck.remaining(), 52);
+    }
+
+    #[test]
+    fn test_try_from_iter_rejects_wrong_count() {
+        let short = Deck::new().take(51);
+        assert!(matches!(
+            Deck::try_from_iter(short),
+            Err(GameError::InvalidDeckSize(51))
+        ));
+
+        let long = Deck::new().chain(Some(Card::new(Suit::Spades, Rank::Ace)));
+        assert!(matches!(
+            Deck::try_from_iter(long),
+            Err(GameError::InvalidDeckSize(53))
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "exactly 52 cards")]
+    fn test_collect_panics_on_wrong_count() {
+        let _: Deck = std::iter::empty().collect();
+    }
+
+    #[test]
+    fn test_shuffled_deck_is_a_permutation() {
+        assert!(Deck::new().is_standard());
+        for seed in 0..100 {
+            let mut deck = Deck::new();
+            deck.shuffle_with_seed(seed);
+            assert!(deck.is_standard());
+        }
+
+        let mut deck = Deck::new();
+        deck.shuffle();
+        assert!(deck.is_standard());
+
+        let aces = [Card::new(Suit::Hearts, Rank::Ace); 52];
+        assert!(matches!(
+            Deck::try_from_iter(aces),
+            Err(GameError::InvalidCard(_))
+        ));
+        assert!(!Deck::from_cards_unchecked(aces).is_standard());
+    }
+
+    #[test]
+    fn test_is_valid_rejects_repeats_and_wrong_sizes() {
+        let mut deck = Deck::new();
+        assert!(deck.is_valid());
+        deck.shuffle_with_seed(7);
+        assert!(deck.is_valid());
+        assert!(Deck::new_with_jokers(MAX_JOKERS).is_valid());
+
+        let mut cards: [Card; 52] = std::array::from_fn(Card::from_index);
+        cards[51] = cards[0];
+        assert!(!Deck::from_cards_unchecked(cards).is_valid());
+        cards[51] = Card::new(Suit::Spades, Rank::Joker);
+        assert!(!Deck::from_cards_unchecked(cards).is_valid());
+
+        let mut short = Deck::new();
+        short.size = 51;
+        assert!(!short.is_valid());
+        let mut jokers = Deck::new_with_jokers(MAX_JOKERS);
+        jokers.cards[53] = jokers.cards[52];
+        assert!(!jokers.is_valid());
+    }
+
+    #[test]
+    fn test_pot_order_changes_next_draws() {
+        let mut pot = RingBuffer::<Card, 52>::new();
+        let ace = Card::new(Suit::Hearts, Rank::Ace);
+        let king = Card::new(Suit::Spades, Rank::King);
+        let three = Card::new(Suit::Hearts, Rank::Three);
+        let four = Card::new(Suit::Spades, Rank::Four);
+        pot.push_back_multiple(&[ace, king, three, four]);
+
+        let draws = |order| {
+            // Player 2 wins the pot into an empty hand
+            let mut hand = PlayerHand::new();
+            hand.take_battle_cards_ordered(&pot, order, 2);
+            [0; 4].map(|_| hand.draw_card().unwrap())
+        };
+
+        assert_eq!(draws(PotOrder::AsPlayed), [ace, king, three, four]);
+        assert_eq!(draws(PotOrder::WinnerFirst), [king, four, ace, three]);
+        assert_eq!(draws(PotOrder::LoserFirst), [ace, three, king, four]);
+    }
+
+    #[test]
+    fn test_take_battle_cards_keeps_play_order() {
+        let [two, five, jack, queen, ace] =
+            [Rank::Two, Rank::Five, Rank::Jack, Rank::Queen, Rank::Ace]
+                .map(|rank| Card::new(Suit::Clubs, rank));
+        let mut hand = PlayerHand::new();
+        hand.add_card(two);
+
+        // A war: jack ties jack, then five loses to ace
+        let mut pot = RingBuffer::<Card, 52>::new();
+        pot.push_back_multiple(&[jack, jack, queen, queen, five, ace]);
+        hand.take_battle_cards(&pot);
+
+        assert_eq!(
+            hand.iter().collect::<Vec<_>>(),
+            [ace, five, queen, queen, jack, jack, two]
+        );
+        assert_eq!(
+            std::iter::from_fn(|| hand.draw_card()).collect::<Vec<_>>(),
+            [two, jack, jack, queen, queen, five, ace]
+        );
+    }
+
+    #[test]
+    fn test_from_cards_rejects_repeats_and_jokers() {
+        let mut cards: [Card; 52] = std::array::from_fn(Card::from_index);
+        let stacked = Deck::from_cards(cards).unwrap();
+        assert!(stacked.is_standard());
+        assert!(stacked.iter().eq(cards));
+
+        cards[51] = cards[0];
+        assert!(matches!(
+            Deck::from_cards(cards),
+            Err(GameError::InvalidCard(_))
+        ));
+        assert!(!Deck::from_cards_unchecked(cards).is_standard());
+
+        cards[51] = Card::new(Suit::Hearts, Rank::Joker);
+        assert!(matches!(
+            Deck::from_cards(cards),
+            Err(GameError::InvalidCard(_))
+        ));
+        assert_eq!(Deck::from_cards_unchecked(cards).remaining(), 52);
+    }
+
+    #[test]
+    fn test_equal_cards_hash_alike() {
+        use std::collections::HashSet;
+
+        let mut seen = HashSet::new();
+        assert!(seen.insert(Card::new(Suit::Clubs, Rank::Queen)));
+        assert!(!seen.insert("QC".parse::<Card>().unwrap()));
+        assert_eq!(seen.len(), 1);
+
+        let deck: HashSet<Card> = Deck::new_with_jokers(MAX_JOKERS).collect();
+        assert_eq!(deck.len(), 54);
+    }
+
+    #[test]
+    fn test_suits_hash_and_sort_in_declared_order() {
+        use std::collections::HashSet;
+
+        let suits: HashSet<Suit> = Deck::new().map(|card| card.suit()).collect();
+        assert_eq!(suits.len(), 4);
+
+        let mut sorted: Vec<Suit> = suits.into_iter().collect();
+        sorted.sort();
+        assert_eq!(
+            sorted,
+            [Suit::Hearts, Suit::Spades, Suit::Clubs, Suit::Diamonds]
+        );
+    }
+
+    #[test]
+    fn test_every_constructed_card_decodes() {
+        for card in Deck::new_with_jokers(MAX_JOKERS) {
+            assert!(Card::is_valid_byte(card.0));
+            assert_eq!(Card::new(card.suit(), card.rank()), card);
+        }
+        for index in 0..56 {
+            assert!(Card::is_valid_byte(Card::from_index(index).0));
+        }
+    }
+
+    #[test]
+    fn test_valid_byte_matches_decoding_for_all_bytes() {
+        for byte in 0..=u8::MAX {
+            let decodes = std::panic::catch_unwind(|| {
+                let card = Card(byte);
+                (card.suit(), card.rank())
+            })
+            .is_ok();
+            assert_eq!(Card::is_valid_byte(byte), decodes, "byte {:#04x}", byte);
+        }
+    }
+
+    #[test]
+    fn test_rank_and_suit_round_trip_through_text() {
+        assert_eq!(Rank::all().count(), 13);
+        for rank in Rank::all() {
+            assert_eq!(rank.to_string().parse::<Rank>().unwrap(), rank);
+            assert_eq!(rank.symbol().parse::<Rank>().unwrap(), rank);
+            assert_eq!(Rank::from_value(rank as u8), Some(rank));
+        }
+        assert_eq!("queen".parse::<Rank>().unwrap(), Rank::Queen);
+        assert_eq!(Rank::Ten.symbol(), "10");
+
+        assert_eq!(Suit::all().count(), 4);
+        for suit in Suit::all() {
+            assert_eq!(suit.to_string().parse::<Suit>().unwrap(), suit);
+            assert_eq!(suit.symbol().parse::<Suit>().unwrap(), suit);
+            assert_eq!(suit.letter().to_lowercase().parse::<Suit>().unwrap(), suit);
+        }
+
+        assert_eq!(
+            "Z".parse::<Rank>(),
+            Err(ParseCardError::UnknownRank("Z".to_string()))
+        );
+        assert_eq!(
+            "".parse::<Suit>(),
+            Err(ParseCardError::UnknownSuit(String::new()))
+        );
+        assert_eq!(Rank::from_value(1), None);
+        assert_eq!(Rank::from_value(15), Some(Rank::Joker));
+        assert_eq!(Rank::from_value(16), None);
+    }
+
+    #[test]
+    fn test_deal_offset_gives_player_2_the_top_card() {
+        let deck = Deck::new();
+        let top = deck.cards[0];
+
+        let (mut player1, mut player2) = deck.split_with_offset(1);
+        let mut hand2 = CardSet::new();
+        while let Some(card) = player2.draw_card() {
+            hand2.insert(card);
+        }
+
+        assert!(hand2.contains(top));
+        assert_eq!(hand2.len(), 26);
+        assert_eq!(player1.len(), 26);
+        assert!(
+            player1
+                .draw_card()
+                .is_some_and(|card| !hand2.contains(card))
+        );
+    }
+
+    #[test]
+    fn test_double_deck_hand_holds_104_cards() {
+        let mut hand = PlayerHand::<104>::empty();
+        for card in Deck::new().chain(Deck::new()) {
+            hand.add_card(card);
+        }
+        assert_eq!(hand.len(), 104);
+        assert!(
+            hand.rank_histogram()[2..=14]
+                .iter()
+                .all(|&count| count == 8)
+        );
+
+        // Counts go past what a byte could hold
+        let mut aces = PlayerHand::<256>::empty();
+        for _ in 0..256 {
+            aces.add_card(Card::new(Suit::Hearts, Rank::Ace));
+        }
+        assert_eq!(aces.rank_histogram()[Rank::Ace as usize], 256);
+
+        // A battle buffer of any size can be collected into it
+        let mut pot = RingBuffer::<Card, 4>::new();
+        pot.push_back(hand.draw_card().unwrap());
+        pot.push_back(hand.draw_card().unwrap());
+        hand.take_battle_cards(&pot);
+        assert_eq!(hand.len(), 104);
+    }
+
+    #[test]
+    fn test_exactly_one_hand_contains_each_card() {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(11);
+        let (player1, player2) = deck.split();
+
+        for card in Deck::new().cards {
+            assert!(player1.contains(card) != player2.contains(card));
+            assert_eq!(player1.count_of(card) + player2.count_of(card), 1);
+        }
+
+        let mut double = PlayerHand::<104>::empty();
+        let ace = Card::new(Suit::Spades, Rank::Ace);
+        double.add_card(ace);
+        double.add_card(ace);
+        assert_eq!(double.count_of(ace), 2);
+    }
+
+    #[test]
+    fn test_deck_codes_round_trip() {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(42);
+
+        let codes = deck.to_codes();
+        assert_eq!(codes.split_whitespace().count(), 52);
+        assert!(codes.split_whitespace().any(|code| code.starts_with("10")));
+
+        let parsed = Deck::from_codes(&codes).unwrap();
+        assert!(parsed.iter().eq(deck.iter()));
+    }
+
+    #[test]
+    fn test_from_codes_rejects_garbage() {
+        assert!(Deck::from_codes("1H").is_err());
+        assert!(Deck::from_codes("AX").is_err());
+        assert!(matches!(
+            Deck::from_codes("AH KH"),
+            Err(GameError::InvalidDeckSize(2))
+        ));
+
+        let repeated = Deck::new().to_codes().replace("KH", "AH");
+        assert!(matches!(
+            Deck::from_codes(&repeated),
+            Err(GameError::InvalidCard(_))
+        ));
+    }
+
+    #[test]
+    fn test_deck_iterates_in_deal_order() {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(3);
+        let expected: Vec<Card> = deck.iter().collect();
+
+        let top: Vec<Card> = deck.by_ref().take(10).collect();
+        assert_eq!(top, expected[..10]);
+        assert_eq!(deck.len(), 42);
+
+        let rest: Vec<Card> = deck.collect();
+        assert_eq!(rest, expected[10..]);
+        assert_eq!(top.len() + rest.len(), 52);
+    }
+
+    #[test]
+    fn test_shuffle_with_rng_matches_seeded_shuffle() {
+        let mut seeded = Deck::new();
+        seeded.shuffle_with_seed(99);
+
+        let mut plugged = Deck::new();
+        plugged.shuffle_with_rng(&mut StdRng::seed_from_u64(99));
+
+        assert!(plugged.iter().eq(seeded.iter()));
+        assert!(plugged.is_standard());
+    }
+
+    #[test]
+    fn test_cards_order_by_rank_then_suit() {
+        assert!(Card::new(Suit::Hearts, Rank::King) > Card::new(Suit::Spades, Rank::Queen));
+        assert!(Card::new(Suit::Diamonds, Rank::Two) < Card::new(Suit::Hearts, Rank::Three));
+
+        // Equal ranks fall back to suit declaration order
+        let hearts = Card::new(Suit::Hearts, Rank::Ace);
+        let spades = Card::new(Suit::Spades, Rank::Ace);
+        assert!(hearts < spades);
+        assert_eq!(hearts.cmp(&hearts), std::cmp::Ordering::Equal);
+        assert_eq!(hearts.rank().cmp(&spades.rank()), std::cmp::Ordering::Equal);
+
+        let mut deck: Vec<Card> = Deck::new().collect();
+        deck.sort();
+        assert_eq!(deck[0], Card::new(Suit::Hearts, Rank::Two));
+        assert_eq!(deck[51], Card::new(Suit::Diamonds, Rank::Ace));
+    }
+
+    #[test]
+    fn test_card_display() {
+        assert_eq!(format!("{}", Card::new(Suit::Hearts, Rank::Ace)), "A♥");
+        assert_eq!(Card::new(Suit::Spades, Rank::Ten).to_string(), "10♠");
+        assert_eq!(Card::new(Suit::Clubs, Rank::Two).to_string(), "2♣");
+    }
+
+    #[test]
+    fn test_u8_round_trip() {
+        for card in Deck::new() {
+            assert_eq!(Card::from_u8(card.as_u8()), Some(card));
+        }
+
+        let valid = (0..=u8::MAX).filter_map(Card::from_u8).count();
+        // 52 standard cards, and a Joker for each set of suit bits
+        assert_eq!(valid, 56);
+        // Rank fields 0, 1 and 16+ are rejected whatever the suit bits
+        for byte in [0, 1 << 2 | 3, 16 << 2, 0xFF] {
+            assert_eq!(Card::from_u8(byte), None, "byte {:#04x}", byte);
+        }
+    }
+
+    #[test]
+    fn test_card_from_str() {
+        assert_eq!("KH".parse(), Ok(Card::new(Suit::Hearts, Rank::King)));
+        assert_eq!("as".parse(), Ok(Card::new(Suit::Spades, Rank::Ace)));
+        assert_eq!("10C".parse(), Ok(Card::new(Suit::Clubs, Rank::Ten)));
+        assert_eq!("2d".parse(), Ok(Card::new(Suit::Diamonds, Rank::Two)));
+        for card in Deck::new() {
+            assert_eq!(card.to_string().parse(), Ok(card));
+            assert_eq!(card.code().parse(), Ok(card));
+        }
+
+        assert_eq!("".parse::<Card>(), Err(ParseCardError::BadLength(0)));
+        assert_eq!("H".parse::<Card>(), Err(ParseCardError::BadLength(1)));
+        assert_eq!("AceH".parse::<Card>(), Err(ParseCardError::BadLength(4)));
+        assert_eq!(
+            "1H".parse::<Card>(),
+            Err(ParseCardError::UnknownRank("1".to_string()))
+        );
+        assert_eq!(
+            "11S".parse::<Card>(),
+            Err(ParseCardError::UnknownRank("11".to_string()))
+        );
+        assert_eq!(
+            "AX".parse::<Card>(),
+            Err(ParseCardError::UnknownSuit("X".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_card_all_is_a_standard_set() {
+        assert_eq!(Card::all().count(), 52);
+        let distinct: CardSet = Card::all().collect();
+        assert!(distinct.is_full());
+        assert!(Card::all().eq(Deck::new()));
+    }
+
+    #[test]
+    fn test_deal_covers_the_deck_for_three_and_four_players() {
+        for (players, sizes) in [(3, vec![18, 17, 17]), (4, vec![13, 13, 13, 13])] {
+            let hands = Deck::new().deal(players);
+            let dealt: Vec<usize> = hands.iter().map(PlayerHand::len).collect();
+            assert_eq!(dealt, sizes);
+            assert_eq!(dealt.iter().sum::<usize>(), 52);
+
+            let mut seen: Vec<Card> = hands.into_iter().flat_map(|hand| hand.cards).collect();
+            let mut all: Vec<Card> = Card::all().collect();
+            seen.sort();
+            all.sort();
+            assert_eq!(seen, all);
+        }
+    }
+
+    #[test]
+    fn test_deal_is_round_robin() {
+        let top: Vec<Card> = Deck::new().take(6).collect();
+        let mut hands = Deck::new().deal_with_offset(3, 1);
+
+        // The first card goes to the second hand, and each hand draws from the back
+        assert_eq!(hands[1].cards.front(), Some(top[0]));
+        assert_eq!(hands[2].cards.front(), Some(top[1]));
+        assert_eq!(hands[0].cards.front(), Some(top[2]));
+        assert_eq!(hands[1].draw_card(), Some(Deck::new().cards[51]));
+    }
+
+    #[test]
+    fn test_shuffled_winnings_keep_every_card() {
+        let mut pot = RingBuffer::<Card, 52>::new();
+        for card in Card::all().take(10) {
+            pot.push_back(card);
+        }
+
+        let take = |seed| {
+            let mut hand = PlayerHand::new();
+            hand.add_card(Card::new(Suit::Clubs, Rank::Ace));
+            hand.take_battle_cards_shuffled(&pot, &mut StdRng::seed_from_u64(seed));
+            hand
+        };
+        let hand = take(1);
+
+        // The pot goes under the existing card, in some order of its own
+        assert_eq!(hand.len(), 11);
+        assert_eq!(hand.cards.back(), Some(Card::new(Suit::Clubs, Rank::Ace)));
+        let mut taken: Vec<Card> = hand.cards.iter().take(10).collect();
+        let mut played: Vec<Card> = pot.iter().collect();
+        taken.sort();
+        played.sort();
+        assert_eq!(taken, played);
+        assert_eq!(take(1).cards, hand.cards);
+    }
+
+    #[test]
+    fn test_peeking_shows_the_next_and_last_draws() {
+        let mut hand = PlayerHand::new();
+        assert_eq!(hand.peek_top(), None);
+        assert_eq!(hand.peek_bottom(), None);
+
+        let cards = [
+            Card::new(Suit::Hearts, Rank::Two),
+            Card::new(Suit::Clubs, Rank::Jack),
+            Card::new(Suit::Spades, Rank::Ace),
+        ];
+        for card in cards {
+            hand.add_card(card);
+        }
+
+        assert_eq!(hand.peek_top(), Some(cards[2]));
+        assert_eq!(hand.peek_bottom(), Some(cards[0]));
+        assert_eq!(hand.len(), 3);
+        assert_eq!(hand.draw_card(), Some(cards[2]));
+        assert_eq!(hand.peek_top(), Some(cards[1]));
+    }
+
+    #[test]
+    fn test_iter_lists_the_hand_bottom_first_without_drawing() {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(9);
+        let (mut hand, _) = deck.split();
+
+        let mut listed: Vec<Card> = hand.iter().collect();
+        assert_eq!(listed.len(), 26);
+        assert_eq!(hand.len(), 26);
+
+        listed.reverse();
+        let drawn: Vec<Card> = std::iter::from_fn(|| hand.draw_card()).collect();
+        assert_eq!(listed, drawn);
+    }
+
+    #[test]
+    fn test_deck_with_jokers_adds_a_red_and_a_black_joker() {
+        let deck = Deck::new_with_jokers(2);
+        assert_eq!(deck.remaining(), 54);
+        assert!(!deck.is_standard());
+
+        let jokers: Vec<Card> = deck.filter(|card| card.rank() == Rank::Joker).collect();
+        assert_eq!(
+            jokers,
+            [
+                Card::new(Suit::Hearts, Rank::Joker),
+                Card::new(Suit::Spades, Rank::Joker)
+            ]
+        );
+        assert_eq!(jokers[0].to_emoji(), '🂿');
+        assert_eq!(jokers[1].to_emoji(), '🃏');
+        assert_eq!(jokers[0].code(), "JKH");
+        assert_eq!("jks".parse(), Ok(jokers[1]));
+
+        let mut shuffled = Deck::new_with_jokers(1);
+        shuffled.shuffle_with_seed(7);
+        let round_trip = Deck::from_codes(&shuffled.to_codes()).unwrap();
+        assert!(shuffled.iter().eq(round_trip.iter()));
+        let (player1, player2) = round_trip.split();
+        assert_eq!((player1.len(), player2.len()), (27, 26));
+
+        // Two red Jokers are as valid as a red and a black one
+        let both_red = [Suit::Hearts, Suit::Diamonds].map(|suit| Card::new(suit, Rank::Joker));
+        let deck = Deck::try_from_iter(Card::all().chain(both_red)).unwrap();
+        assert!(deck.is_valid());
+    }
+
+    #[test]
+    #[should_panic(expected = "at most 2 Jokers")]
+    fn test_deck_rejects_too_many_jokers() {
+        Deck::new_with_jokers(3);
+    }
 }
@@ -0,0 +1,367 @@
+use crate::cards::ValueTable;
+use crate::events::OutputFormat;
+use crate::game::{GameConfig, GameError, GameResult, MAX_PLAYERS};
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+/// Largest possible gap between two card values (Ace - Two)
+const MAX_VALUE_GAP: u8 = 12;
+
+#[derive(Parser, Debug)]
+#[command(name = "war-rust")]
+#[command(about = "A War card game implementation in Rust")]
+#[command(version = "0.1.0")]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Enable test mode (game ends after 20 rounds)
+    #[arg(short, long)]
+    pub test: bool,
+
+    /// End the game after this many rounds (default: 20 with --test, 10000 otherwise)
+    #[arg(long, value_name = "N")]
+    pub max_rounds: Option<usize>,
+
+    /// Enable interactive mode (press SPACE to continue each round)
+    #[arg(short, long)]
+    pub interactive: bool,
+
+    /// Enable hot seat mode (each player presses SPACE to reveal their card)
+    #[arg(long)]
+    pub hot_seat: bool,
+
+    /// Set random seed for deterministic gameplay
+    #[arg(short, long)]
+    pub seed: Option<u64>,
+
+    /// Play today's daily challenge (everyone gets the same deal each UTC day)
+    #[arg(long, conflicts_with = "seed")]
+    pub daily: bool,
+
+    /// Derive the seed from a memorable phrase, so a deal can be shared by name
+    #[arg(long, conflicts_with_all = ["seed", "daily"])]
+    pub passphrase: Option<String>,
+
+    /// Start a war when card values differ by at most this much (0 = exact ties only)
+    #[arg(long, default_value_t = 0)]
+    pub war_tolerance: u8,
+
+    /// Reveal the deciding war cards slowly for dramatic effect
+    #[arg(long)]
+    pub animate: bool,
+
+    /// Score chips for each pot won and end the game when a player reaches this many
+    #[arg(long)]
+    pub target_score: Option<u32>,
+
+    /// Show burned war cards face up instead of as "??"
+    #[arg(long)]
+    pub show_burns: bool,
+
+    /// Play Aces as the lowest card instead of the highest
+    #[arg(long)]
+    pub ace_low: bool,
+
+    /// Which player is dealt the top card of the deck (at most --players)
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=MAX_PLAYERS as i64))]
+    pub first_player: u8,
+
+    /// Number of players at the table; with more than two, the highest card
+    /// wins and only the tied players go to war
+    #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u8).range(2..=MAX_PLAYERS as i64))]
+    pub players: u8,
+
+    /// Narrate the game in prose, or print one JSON object per game event
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    #[arg(conflicts_with_all = ["interactive", "hot_seat", "animate"])]
+    pub format: OutputFormat,
+
+    /// Print only the final result line instead of narrating every round
+    #[arg(short, long, conflicts_with_all = ["interactive", "hot_seat", "animate", "format"])]
+    pub quiet: bool,
+
+    /// End the game as a draw if the hands ever repeat a position (uses ~20 bytes per round)
+    #[arg(long)]
+    pub detect_cycles: bool,
+
+    /// Shuffle each won pot before taking it, so games can't loop forever
+    #[arg(long)]
+    pub shuffle_winnings: bool,
+
+    /// Save the game to FILE after every round, so it can be resumed with --load
+    #[arg(long, value_name = "FILE")]
+    pub save: Option<PathBuf>,
+
+    /// Resume a game saved with --save; the rules come from FILE, the
+    /// display options from the command line
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["seed", "daily", "passphrase", "players"])]
+    pub load: Option<PathBuf>,
+
+    /// Record the game to FILE, to watch again with `war-rust replay FILE`
+    #[arg(long, value_name = "FILE", conflicts_with = "load")]
+    pub record: Option<PathBuf>,
+
+    /// Print a table of game statistics (wars, largest lead, ...) at the end
+    #[arg(long, conflicts_with = "format")]
+    pub stats: bool,
+
+    /// Play one headless game and print how long each phase took
+    #[arg(long, conflicts_with_all = ["tournament", "compare_seeds"])]
+    pub profile: bool,
+
+    /// Print the shuffled deck and both dealt hands as card codes, without playing
+    #[arg(long, conflicts_with_all = ["tournament", "compare_seeds", "profile"])]
+    pub export_deck: bool,
+
+    /// Run a single-elimination bracket of this many seeded players (a power of two)
+    #[arg(long, conflicts_with_all = ["interactive", "hot_seat"])]
+    pub tournament: Option<usize>,
+
+    /// Simulate two seeds and print their outcomes side by side
+    #[arg(long, num_args = 2, value_names = ["SEED_A", "SEED_B"], conflicts_with = "tournament")]
+    pub compare_seeds: Option<Vec<u64>>,
+
+    /// Simulate every seed in this file (one per line) and print a result line for each
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["tournament", "compare_seeds", "profile"])]
+    pub seeds_from_file: Option<PathBuf>,
+
+    /// Play a headless game, broadcasting each round as JSON to TCP spectators on this address
+    #[cfg(feature = "spectate")]
+    #[arg(long, value_name = "ADDR", conflicts_with_all = ["tournament", "compare_seeds"])]
+    pub spectate: Option<String>,
+
+    /// Play in a full-screen terminal interface (SPACE plays a round, q quits)
+    #[cfg(feature = "tui")]
+    #[arg(long, conflicts_with_all = ["tournament", "compare_seeds"])]
+    pub tui: bool,
+}
+
+/// Things to do instead of playing a new game
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Narrate a game recorded with --record again, without shuffling anything
+    Replay {
+        /// The file written by --record
+        file: PathBuf,
+
+        /// Also play the recorded seed again and check the engine still
+        /// plays every round as recorded
+        #[arg(long)]
+        verify: bool,
+    },
+
+    /// Estimate each player's odds by simulating many shuffled deals,
+    /// seeded from --seed onwards
+    Analyze {
+        /// How many games to simulate
+        #[arg(long, default_value_t = 10000, value_parser = clap::value_parser!(u64).range(1..))]
+        samples: u64,
+    },
+}
+
+impl Args {
+    /// True if the command line picks a mode that simulates two-player games
+    /// instead of playing one at the terminal
+    fn is_headless(&self) -> bool {
+        let headless = matches!(self.command, Some(Command::Analyze { .. }))
+            || self.tournament.is_some()
+            || self.profile
+            || self.seeds_from_file.is_some()
+            || self.compare_seeds.is_some();
+        #[cfg(feature = "spectate")]
+        let headless = headless || self.spectate.is_some();
+        #[cfg(feature = "tui")]
+        let headless = headless || self.tui;
+        headless
+    }
+}
+
+impl GameConfig {
+    /// Validate the command line and map it onto a game configuration
+    pub fn from_args(args: &Args) -> GameResult<GameConfig> {
+        if args.war_tolerance >= MAX_VALUE_GAP {
+            return Err(GameError::InvalidConfig(format!(
+                "--war-tolerance {} would make every round a war (must be below {})",
+                args.war_tolerance, MAX_VALUE_GAP
+            )));
+        }
+
+        if args.target_score == Some(0) {
+            return Err(GameError::InvalidConfig(
+                "--target-score must be at least 1".to_string(),
+            ));
+        }
+
+        if args.max_rounds == Some(0) {
+            return Err(GameError::InvalidConfig(
+                "--max-rounds must be at least 1".to_string(),
+            ));
+        }
+
+        if args.first_player > args.players {
+            return Err(GameError::InvalidConfig(format!(
+                "--first-player {} is not at a table of {} players",
+                args.first_player, args.players
+            )));
+        }
+
+        if args.players != 2 && args.is_headless() {
+            return Err(GameError::InvalidConfig(format!(
+                "--players {} only applies to a game at the terminal; simulations seat two players",
+                args.players
+            )));
+        }
+
+        Ok(GameConfig {
+            test_mode: args.test,
+            interactive: args.interactive,
+            hot_seat: args.hot_seat,
+            war_tolerance: args.war_tolerance,
+            animate: args.animate,
+            target_score: args.target_score,
+            show_burns: args.show_burns,
+            values: if args.ace_low {
+                ValueTable::ace_low()
+            } else {
+                ValueTable::default()
+            },
+            deal_offset: usize::from(args.first_player - 1),
+            players: usize::from(args.players),
+            format: args.format,
+            quiet: args.quiet,
+            detect_cycles: args.detect_cycles,
+            shuffle_winnings: args.shuffle_winnings,
+            max_rounds: args.max_rounds,
+            ..GameConfig::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::Deck;
+    use crate::game::{EndReason, WarGame};
+    use crate::simulation::compare_seeds;
+
+    #[test]
+    fn test_from_args_maps_flags() {
+        let args = Args::parse_from(["war-rust", "--hot-seat", "--war-tolerance", "2"]);
+        let config = GameConfig::from_args(&args).unwrap();
+
+        assert!(config.hot_seat);
+        assert!(!config.test_mode);
+        assert!(!config.interactive);
+        assert_eq!(config.war_tolerance, 2);
+    }
+
+    #[test]
+    fn test_from_args_rejects_tolerance_covering_every_card() {
+        let args = Args::parse_from(["war-rust", "--war-tolerance", "12"]);
+
+        match GameConfig::from_args(&args) {
+            Err(GameError::InvalidConfig(message)) => assert!(message.contains("--war-tolerance")),
+            other => panic!("expected InvalidConfig, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_json_format_rules_out_prompts() {
+        let args = Args::parse_from(["war-rust", "--format", "json"]);
+        assert_eq!(
+            GameConfig::from_args(&args).unwrap().format,
+            OutputFormat::Json
+        );
+
+        assert!(Args::try_parse_from(["war-rust", "--format", "json", "--interactive"]).is_err());
+    }
+
+    #[test]
+    fn test_ace_low_flag_sets_the_value_table() {
+        let args = Args::parse_from(["war-rust", "--ace-low"]);
+        assert_eq!(
+            GameConfig::from_args(&args).unwrap().values,
+            ValueTable::ace_low()
+        );
+
+        let args = Args::parse_from(["war-rust"]);
+        assert_eq!(
+            GameConfig::from_args(&args).unwrap().values,
+            ValueTable::identity()
+        );
+    }
+
+    #[test]
+    fn test_headless_modes_reject_other_table_sizes() {
+        for headless in [
+            &["analyze"][..],
+            &["--tournament", "4"],
+            &["--profile"],
+            &["--compare-seeds", "1", "2"],
+        ] {
+            let args = Args::parse_from(["war-rust", "--players", "3"].iter().chain(headless));
+            assert!(
+                matches!(
+                    GameConfig::from_args(&args),
+                    Err(GameError::InvalidConfig(_))
+                ),
+                "accepted {:?}",
+                headless
+            );
+        }
+
+        // The rules still reach the headless modes
+        let args = Args::parse_from(["war-rust", "--detect-cycles", "--profile"]);
+        assert!(GameConfig::from_args(&args).unwrap().detect_cycles);
+        let args = Args::parse_from(["war-rust", "--players", "3"]);
+        assert_eq!(GameConfig::from_args(&args).unwrap().players, 3);
+
+        // --test caps a headless game at 20 rounds, as it does at the terminal
+        let args = Args::parse_from(["war-rust", "--test", "--compare-seeds", "23", "1"]);
+        let config = GameConfig::from_args(&args).unwrap();
+        assert_eq!(config.round_limit(), 20);
+        let comparison = compare_seeds(23, 1, config, config.round_limit());
+        assert!(
+            comparison
+                .summaries
+                .iter()
+                .all(|summary| summary.rounds == 20)
+        );
+        let args = Args::parse_from(["war-rust", "--test", "--max-rounds", "50"]);
+        assert_eq!(GameConfig::from_args(&args).unwrap().round_limit(), 50);
+    }
+
+    #[test]
+    fn test_any_seat_can_take_the_first_card() {
+        let args = Args::parse_from(["war-rust", "--players", "3", "--first-player", "3"]);
+        let config = GameConfig::from_args(&args).unwrap();
+        let hands = Deck::new().deal_with_offset(config.players, config.deal_offset);
+        // Hands list their cards bottom first, the first dealt lowest
+        assert_eq!(hands[2].iter().next(), Deck::new().next());
+
+        let args = Args::parse_from(["war-rust", "--first-player", "3"]);
+        assert!(matches!(
+            GameConfig::from_args(&args),
+            Err(GameError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_max_rounds_flag_caps_the_game() {
+        let args = Args::parse_from(["war-rust", "--quiet", "--max-rounds", "50"]);
+        let config = GameConfig::from_args(&args).unwrap();
+        assert_eq!(config.max_rounds, Some(50));
+
+        // Seed 42 runs for hundreds of rounds when left alone
+        let outcome = WarGame::new_with_seed(config, 42).play().unwrap();
+        assert_eq!(outcome.rounds, 50);
+        assert_eq!(outcome.reason, EndReason::TruncatedByLimit);
+
+        let args = Args::parse_from(["war-rust", "--max-rounds", "0"]);
+        assert!(matches!(
+            GameConfig::from_args(&args),
+            Err(GameError::InvalidConfig(_))
+        ));
+    }
+}
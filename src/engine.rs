@@ -0,0 +1,611 @@
+use crate::card_set::CardSet;
+use crate::cards::{Card, Deck, MAX_DECK_SIZE, PlayedCard, PlayerHand, PotOrder};
+use crate::daily::Fnv1a;
+use crate::game::{
+    EndReason, GameConfig, GameError, GameOutcome, GameResult, MAX_PLAYERS, Seats, resolve_trick,
+};
+use crate::observer::{GameObserver, NullObserver};
+use crate::ring_buffer::RingBuffer;
+use crate::save::SavedGame;
+use crate::stats::{GameStats, count_leader};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::collections::HashSet;
+use std::hash::{DefaultHasher, Hasher};
+
+/// A pause in the middle of a round, where `WarGame` may wait for a keypress
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Pause {
+    /// `player`'s card is about to be turned up
+    Reveal(usize),
+    /// A war was declared, or the round is over
+    Continue,
+}
+
+/// What a call to `GameEngine::step` did
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StepResult {
+    /// A round was played and the game goes on
+    Played,
+    /// The game is over: this step ended it, or it already had
+    Finished(GameOutcome),
+}
+
+/// The rules of War with no terminal attached: the hands, the pot, and
+/// everything needed to play them out one round at a time
+/// Nothing is printed or read; `step` plays a round silently and
+/// `step_observed` reports it to a `GameObserver`. `WarGame` wraps an engine
+/// with keypress prompts and console narration for the command line
+/// The pot holds up to `POT` cards; a war that outgrows it fails with
+/// `GameError::BattleBufferFull` rather than losing cards
+#[derive(Clone)]
+pub struct GameEngine<const POT: usize = MAX_DECK_SIZE> {
+    /// One hand per seat; seats past `config.players` stay empty
+    pub(crate) hands: [PlayerHand; MAX_PLAYERS],
+    pub(crate) battle_buffer: RingBuffer<Card, POT>,
+    /// Cards dealt at the start; play must never create or lose any
+    pub(crate) deck_size: usize,
+    pub(crate) round: usize,
+    end_reason: Option<EndReason>,
+    winner: Option<usize>,
+    stats: GameStats,
+    pub(crate) config: GameConfig,
+    /// Fingerprints of the positions seen between rounds, when detecting cycles
+    seen_positions: HashSet<u64>,
+    /// Orders won pots when `config.shuffle_winnings` is set
+    winnings_rng: StdRng,
+}
+
+/// The generator for `GameConfig::shuffle_winnings`, seeded from the dealt
+/// hands so that the same deal always gathers its pots the same way
+fn winnings_rng<'a>(hands: impl IntoIterator<Item = &'a PlayerHand>) -> StdRng {
+    let mut fingerprint = Fnv1a::default();
+    for hand in hands {
+        hand.hash_cards(&mut fingerprint);
+    }
+    StdRng::seed_from_u64(fingerprint.finish())
+}
+
+impl GameEngine {
+    /// Deal a prepared deck
+    pub fn from_deck(deck: Deck, config: GameConfig) -> Self {
+        Self::from_deck_with_pot(deck, config)
+    }
+}
+
+impl<const POT: usize> GameEngine<POT> {
+    /// Like `from_deck`, with a pot of any capacity, e.g.
+    /// `GameEngine::<104>::from_deck_with_pot(deck, config)`
+    /// Panics if `config.players` is outside `2..=MAX_PLAYERS`
+    pub fn from_deck_with_pot(deck: Deck, config: GameConfig) -> Self {
+        assert!(
+            (2..=MAX_PLAYERS).contains(&config.players),
+            "War needs 2 to {} players, got {}",
+            MAX_PLAYERS,
+            config.players
+        );
+        let mut dealt = deck
+            .deal_with_offset(config.players, config.deal_offset)
+            .into_iter();
+        let hands: [PlayerHand; MAX_PLAYERS] =
+            std::array::from_fn(|_| dealt.next().unwrap_or_default());
+        Self::from_hands(hands, 0, GameStats::default(), config)
+    }
+
+    /// Pick up a saved game where it left off, under the rules it was saved with
+    /// Shuffled winnings are reseeded from the saved hands, so a resumed
+    /// `shuffle_winnings` game plays on differently than it would have
+    pub fn resume(saved: &SavedGame) -> GameResult<Self> {
+        let players = saved.hands.len();
+        if !(2..=MAX_PLAYERS).contains(&players) {
+            return Err(GameError::InvalidSave(format!(
+                "War needs 2 to {} players, got {}",
+                MAX_PLAYERS, players
+            )));
+        }
+        let deck_size: usize = saved.hands.iter().map(Vec::len).sum();
+        if deck_size > MAX_DECK_SIZE {
+            return Err(GameError::InvalidDeckSize(deck_size));
+        }
+        if saved.scores.len() != players {
+            return Err(GameError::InvalidSave(format!(
+                "{} scores for {} players",
+                saved.scores.len(),
+                players
+            )));
+        }
+
+        let mut seen = CardSet::new();
+        let mut hands: [PlayerHand; MAX_PLAYERS] = Default::default();
+        for (hand, cards) in hands.iter_mut().zip(&saved.hands) {
+            for &card in cards {
+                if !seen.insert(card) {
+                    return Err(GameError::InvalidSave(format!("{} is dealt twice", card)));
+                }
+                hand.add_card(card);
+            }
+        }
+        let mut stats = GameStats::default();
+        stats.scores[..players].copy_from_slice(&saved.scores);
+        Ok(Self::from_hands(hands, saved.round, stats, saved.config))
+    }
+
+    pub(crate) fn from_hands(
+        hands: [PlayerHand; MAX_PLAYERS],
+        round: usize,
+        stats: GameStats,
+        config: GameConfig,
+    ) -> Self {
+        let deck_size = hands.iter().map(PlayerHand::len).sum();
+        let winnings_rng = winnings_rng(&hands);
+        let mut engine = GameEngine {
+            hands,
+            battle_buffer: RingBuffer::new(),
+            deck_size,
+            round,
+            end_reason: None,
+            winner: None,
+            stats,
+            config,
+            seen_positions: HashSet::new(),
+            winnings_rng,
+        };
+        engine.position_repeats();
+        engine
+    }
+
+    /// The game as it stands between rounds, for `resume`
+    pub fn saved(&self) -> SavedGame {
+        SavedGame {
+            round: self.round,
+            hands: self
+                .players()
+                .map(|player| self.hands[player - 1].iter().collect())
+                .collect(),
+            scores: self.stats.scores[..self.config.players].to_vec(),
+            config: self.config,
+        }
+    }
+
+    /// The settings the game is played under
+    pub fn config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    /// Rounds played so far
+    pub fn round(&self) -> usize {
+        self.round
+    }
+
+    /// Player numbers at the table, from 1
+    fn players(&self) -> std::ops::RangeInclusive<usize> {
+        1..=self.config.players
+    }
+
+    /// Cards held by each seated player, player 1 first
+    pub fn card_counts(&self) -> Seats<usize> {
+        self.players()
+            .map(|player| self.hands[player - 1].len())
+            .collect()
+    }
+
+    /// The only player still holding cards, once everyone else has run out
+    fn last_player_standing(&self) -> Option<usize> {
+        let mut holding = self
+            .players()
+            .filter(|&player| !self.hands[player - 1].is_empty());
+        match (holding.next(), holding.next()) {
+            (Some(player), None) => Some(player),
+            _ => None,
+        }
+    }
+
+    /// Turn `player`'s card up, once `pause` lets it be seen
+    fn reveal(
+        observer: &mut dyn GameObserver,
+        pause: &mut dyn FnMut(Pause) -> GameResult<()>,
+        player: usize,
+        card: Card,
+    ) -> GameResult<()> {
+        pause(Pause::Reveal(player))?;
+        observer.on_card_drawn(
+            player,
+            PlayedCard {
+                card,
+                face_up: true,
+            },
+        );
+        Ok(())
+    }
+
+    /// `player`'s hand; seats past `config.players` hold an empty one
+    pub(crate) fn hand(&self, player: usize) -> &PlayerHand {
+        &self.hands[player - 1]
+    }
+
+    fn hand_mut(&mut self, player: usize) -> GameResult<&mut PlayerHand> {
+        if self.players().contains(&player) {
+            Ok(&mut self.hands[player - 1])
+        } else {
+            Err(GameError::InvalidPlayerNumber(player))
+        }
+    }
+
+    fn draw_card(&mut self, player: usize) -> GameResult<Option<Card>> {
+        Ok(self.hand_mut(player)?.draw_card())
+    }
+
+    /// Draw `tied[index]`'s next war card, dropping them from the war if
+    /// they have run out
+    fn draw_for_war(
+        &mut self,
+        observer: &mut dyn GameObserver,
+        tied: &mut Seats<usize>,
+        index: usize,
+    ) -> GameResult<Option<Card>> {
+        let player = tied[index];
+        let card = self.draw_card(player)?;
+        if card.is_none() {
+            observer.on_out_of_cards(player);
+            tied.remove(index);
+        }
+        Ok(card)
+    }
+
+    fn add_cards_to_winner(
+        &mut self,
+        observer: &mut dyn GameObserver,
+        winner: usize,
+    ) -> GameResult<()> {
+        let pot_size = self.battle_buffer.len();
+        // Winner- and loser-first ordering rely on the pot alternating
+        // between two players, so larger tables take it as played
+        let pot_order = if self.config.players == 2 {
+            self.config.pot_order
+        } else {
+            PotOrder::AsPlayed
+        };
+        self.hand_mut(winner)?;
+        observer.on_round_won(self.round, winner, pot_size);
+        // The pot is cleared each round and only pushed to, so it never wraps
+        let (pot, wrapped) = self.battle_buffer.as_slices();
+        debug_assert!(wrapped.is_empty());
+        observer.on_pot_won(winner, pot);
+        let hand = &mut self.hands[winner - 1];
+        if self.config.shuffle_winnings {
+            hand.take_battle_cards_shuffled(&self.battle_buffer, &mut self.winnings_rng);
+        } else {
+            hand.take_battle_cards_ordered(&self.battle_buffer, pot_order, winner);
+        }
+        self.battle_buffer.clear();
+        self.stats.record_pot(winner, pot_size);
+        if let Some(length) = self.stats.record_round_winner(winner) {
+            observer.on_streak(winner, length);
+        }
+        self.stats
+            .record_card_counts(self.round, &self.card_counts());
+        self.debug_check_card_count();
+        Ok(())
+    }
+
+    /// Everyone else in the war has run out of cards: `winner` takes the
+    /// whole pot, and the game too if nobody else is left holding cards
+    fn forfeit_war(
+        &mut self,
+        observer: &mut dyn GameObserver,
+        winner: usize,
+        levels: usize,
+    ) -> GameResult<Option<usize>> {
+        self.stats.record_war(self.battle_buffer.len(), levels);
+        self.add_cards_to_winner(observer, winner)?;
+        Ok(self.last_player_standing())
+    }
+
+    /// Add a played card to the pot, failing loudly rather than dropping it
+    fn push_to_pot(&mut self, card: Card) -> GameResult<()> {
+        if self.battle_buffer.push_back(card) {
+            Ok(())
+        } else {
+            Err(GameError::BattleBufferFull)
+        }
+    }
+
+    /// Debug builds panic as soon as a card is created or lost,
+    /// rather than the drift only showing up in the final counts
+    fn debug_check_card_count(&self) {
+        debug_assert_eq!(
+            self.hands.iter().map(PlayerHand::len).sum::<usize>() + self.battle_buffer.len(),
+            self.deck_size,
+            "card count drifted in round {}",
+            self.round
+        );
+    }
+
+    /// Play one round, reporting to `observer` and pausing where a
+    /// `WarGame` may wait for a keypress; returns the winner once only one
+    /// player holds cards
+    pub(crate) fn play_round(
+        &mut self,
+        observer: &mut dyn GameObserver,
+        pause: &mut dyn FnMut(Pause) -> GameResult<()>,
+    ) -> GameResult<Option<usize>> {
+        self.round += 1;
+
+        if let Some(winner) = self.last_player_standing() {
+            return Ok(Some(winner));
+        }
+
+        let card_counts = self.card_counts();
+        self.stats.record_round_start(&card_counts);
+        observer.on_round_start(self.round, &card_counts);
+
+        // Clear and reuse the battle buffer
+        self.battle_buffer.clear();
+
+        // Draw initial cards; players who are out sit the round out
+        let mut played = Seats::new();
+        for player in self.players() {
+            if let Some(card) = self.hands[player - 1].draw_card() {
+                played.push((player, card));
+            }
+        }
+        for &(player, card) in &played {
+            Self::reveal(observer, pause, player, card)?;
+        }
+        for &(_, card) in &played {
+            self.push_to_pot(card)?;
+        }
+        observer.on_cards_revealed(&played);
+
+        let mut tied = resolve_trick(&played, &self.config);
+        if let [winner] = tied[..] {
+            self.add_cards_to_winner(observer, winner)?;
+        } else {
+            observer.on_war(&tied, self.battle_buffer.len());
+            pause(Pause::Continue)?;
+            let mut levels = 1;
+
+            // Keep burning cards and drawing new deciders until the war is won;
+            // the pot accumulates in the battle buffer throughout
+            loop {
+                for i in 1..=self.config.war_variant.burns() {
+                    let mut index = 0;
+                    while index < tied.len() {
+                        let player = tied[index];
+                        if let Some(burn) = self.draw_for_war(observer, &mut tied, index)? {
+                            self.push_to_pot(burn)?;
+                            observer.on_card_burned(player, i, self.config.burn(burn));
+                            index += 1;
+                        } else if let [winner] = tied[..] {
+                            return self.forfeit_war(observer, winner, levels);
+                        }
+                    }
+                }
+
+                // Draw the deciding cards
+                let mut deciders = Seats::new();
+                let mut index = 0;
+                while index < tied.len() {
+                    let player = tied[index];
+                    if let Some(card) = self.draw_for_war(observer, &mut tied, index)? {
+                        deciders.push((player, card));
+                        index += 1;
+                    } else if let [winner] = tied[..] {
+                        // Deciders already drawn are off their hands, so they join the pot
+                        for &(_, card) in &deciders {
+                            self.push_to_pot(card)?;
+                        }
+                        return self.forfeit_war(observer, winner, levels);
+                    }
+                }
+                for &(player, card) in &deciders {
+                    Self::reveal(observer, pause, player, card)?;
+                }
+                for &(_, card) in &deciders {
+                    self.push_to_pot(card)?;
+                }
+                observer.on_cards_revealed(&deciders);
+
+                tied = resolve_trick(&deciders, &self.config);
+                if let [winner] = tied[..] {
+                    self.stats.record_war(self.battle_buffer.len(), levels);
+                    self.add_cards_to_winner(observer, winner)?;
+                    break;
+                }
+                levels += 1;
+                observer.on_war(&tied, self.battle_buffer.len());
+                pause(Pause::Continue)?;
+            }
+        }
+
+        pause(Pause::Continue)?;
+        // The game is over as soon as the pot leaves one player holding
+        // everything, not a round later
+        Ok(self.last_player_standing())
+    }
+
+    /// Play one round silently
+    pub fn step(&mut self) -> GameResult<StepResult> {
+        self.step_observed(&mut NullObserver)
+    }
+
+    /// Play one round, reporting it to `observer`; the round that ends the
+    /// game also reports `on_game_over`
+    pub fn step_observed(&mut self, observer: &mut dyn GameObserver) -> GameResult<StepResult> {
+        self.step_with(observer, &mut |_| Ok(()))
+    }
+
+    /// Like `step_observed`, calling `pause` wherever a player might be
+    /// kept waiting
+    pub(crate) fn step_with(
+        &mut self,
+        observer: &mut dyn GameObserver,
+        pause: &mut dyn FnMut(Pause) -> GameResult<()>,
+    ) -> GameResult<StepResult> {
+        if let Some(outcome) = self.outcome() {
+            return Ok(StepResult::Finished(outcome));
+        }
+
+        let (winner, reason) = if let Some(winner) = self.play_round(observer, pause)? {
+            (Some(winner), EndReason::OutOfCards)
+        } else if let Some(winner) = self.target_score_reached() {
+            (Some(winner), EndReason::TargetScore)
+        } else if self.position_repeats() {
+            (None, EndReason::Cycle)
+        } else if self.round >= self.config.round_limit() {
+            // Wars never span rounds, so the pot is already distributed
+            // (see `GameConfig::max_rounds`)
+            debug_assert!(self.battle_buffer.is_empty());
+            (
+                count_leader(&self.card_counts()),
+                EndReason::TruncatedByLimit,
+            )
+        } else {
+            return Ok(StepResult::Played);
+        };
+        Ok(StepResult::Finished(self.finish(observer, winner, reason)))
+    }
+
+    /// Returns true once the game has been played to completion
+    pub fn is_finished(&self) -> bool {
+        self.end_reason.is_some()
+    }
+
+    /// How the game ended, once it has finished
+    pub fn outcome(&self) -> Option<GameOutcome> {
+        Some(GameOutcome {
+            winner: self.winner,
+            rounds: self.round,
+            reason: self.end_reason?,
+            final_counts: self.card_counts(),
+        })
+    }
+
+    /// The winning player once the game has finished, `None` while it is in progress
+    /// If the round limit ends the game, the player holding the most cards
+    /// wins (still `None` when the lead is shared)
+    pub fn winner(&self) -> Option<usize> {
+        self.winner
+    }
+
+    /// Statistics gathered so far
+    pub fn stats(&self) -> &GameStats {
+        &self.stats
+    }
+
+    /// Fingerprint of every hand in draw order; the pot is always empty
+    /// between rounds, so this fixes how the rest of the game plays out
+    fn position(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        for hand in &self.hands[..self.config.players] {
+            hand.hash_cards(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// Record the current position, returning true if it has been seen before
+    /// Fingerprint collisions are possible in principle, but at a few
+    /// thousand positions per game the odds are around 1 in 10^12
+    fn position_repeats(&mut self) -> bool {
+        if !self.config.detect_cycles || self.config.target_score.is_some() {
+            return false;
+        }
+        let position = self.position();
+        !self.seen_positions.insert(position)
+    }
+
+    /// The player whose chip score has reached the target, if scoring is enabled
+    fn target_score_reached(&self) -> Option<usize> {
+        let target = self.config.target_score?;
+        self.players()
+            .find(|&player| self.stats.score(player) >= u64::from(target))
+    }
+
+    fn finish(
+        &mut self,
+        observer: &mut dyn GameObserver,
+        winner: Option<usize>,
+        reason: EndReason,
+    ) -> GameOutcome {
+        self.end_reason = Some(reason);
+        self.winner = winner;
+        let outcome = GameOutcome {
+            winner,
+            rounds: self.round,
+            reason,
+            final_counts: self.card_counts(),
+        };
+        observer.on_game_over(&outcome, &self.stats);
+        outcome
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resume_rejects_inconsistent_saves() {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(42);
+        let saved = GameEngine::from_deck(deck, GameConfig::default()).saved();
+        assert!(GameEngine::<MAX_DECK_SIZE>::resume(&saved).is_ok());
+
+        let mut short_scores = saved.clone();
+        short_scores.scores.pop();
+        let mut repeated_card = saved.clone();
+        let card = repeated_card.hands[0][0];
+        repeated_card.hands[1][0] = card;
+
+        for damaged in [short_scores, repeated_card] {
+            assert!(matches!(
+                GameEngine::<MAX_DECK_SIZE>::resume(&damaged),
+                Err(GameError::InvalidSave(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_engine_step_reports_one_round_to_the_observer() {
+        #[derive(Default)]
+        struct Counter {
+            rounds: usize,
+            wins: usize,
+            games_over: usize,
+        }
+        impl GameObserver for Counter {
+            fn on_round_start(&mut self, _: usize, _: &[usize]) {
+                self.rounds += 1;
+            }
+            fn on_round_won(&mut self, _: usize, _: usize, _: usize) {
+                self.wins += 1;
+            }
+            fn on_game_over(&mut self, _: &GameOutcome, _: &GameStats) {
+                self.games_over += 1;
+            }
+        }
+
+        let config = GameConfig {
+            max_rounds: Some(2),
+            ..GameConfig::default()
+        };
+        let mut engine = GameEngine::from_deck(Deck::new(), config);
+        let mut counter = Counter::default();
+
+        assert_eq!(
+            engine.step_observed(&mut counter).unwrap(),
+            StepResult::Played
+        );
+        assert_eq!(
+            (counter.rounds, counter.wins, counter.games_over),
+            (1, 1, 0)
+        );
+        assert_eq!(engine.card_counts().iter().sum::<usize>(), 52);
+
+        let StepResult::Finished(outcome) = engine.step_observed(&mut counter).unwrap() else {
+            panic!("the round limit should end the game");
+        };
+        assert_eq!(outcome.reason, EndReason::TruncatedByLimit);
+        assert_eq!((counter.rounds, counter.games_over), (2, 1));
+    }
+}
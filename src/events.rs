@@ -0,0 +1,348 @@
+use crate::cards::PlayedCard;
+use crate::game::{EndReason, GameOutcome, GameResult};
+use crate::observer::{GameObserver, player_list};
+use crate::save::{Json, invalid, small};
+use crate::stats::GameStats;
+use std::fmt;
+
+/// How a `WarGame` reports play on stdout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable narration
+    #[default]
+    Text,
+    /// One JSON object per `GameEvent`, one per line, and no narration
+    Json,
+}
+
+/// Something that happened during a `WarGame`, for machine consumption
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameEvent {
+    /// A round is about to be played; `card_counts` lists each player's
+    /// cards, player 1 first
+    RoundStart {
+        round: usize,
+        card_counts: Vec<usize>,
+    },
+    /// A player put a card on the table; burned cards stay hidden unless
+    /// `show_burns` is set
+    CardDrawn { player: usize, card: PlayedCard },
+    /// `players` tied and are going to war over `pot_size` cards
+    War {
+        players: Vec<usize>,
+        pot_size: usize,
+    },
+    /// `winner` took a pot of `pot_size` cards, ending `round`
+    RoundWon {
+        round: usize,
+        winner: usize,
+        pot_size: usize,
+    },
+    /// The game ended after `rounds` rounds (`winner` is `None` for a tie)
+    GameOver {
+        winner: Option<usize>,
+        rounds: usize,
+        reason: EndReason,
+    },
+}
+
+impl GameEvent {
+    /// Render as a single-line JSON object, tagged by its `"event"` field
+    pub fn to_json(&self) -> String {
+        self.json(false)
+    }
+
+    /// Like `to_json`, but naming face-down cards too, for logs that must
+    /// be read back with `from_json`
+    pub(crate) fn to_log_json(&self) -> String {
+        self.json(true)
+    }
+
+    fn json(&self, reveal: bool) -> String {
+        match self {
+            GameEvent::RoundStart { round, card_counts } => format!(
+                "{{\"event\":\"round_start\",\"round\":{},\"card_counts\":{}}}",
+                round,
+                json_list(card_counts)
+            ),
+            GameEvent::CardDrawn { player, card } => {
+                let code = if card.face_up || reveal {
+                    format!("\"{}\"", card.card.code())
+                } else {
+                    "null".to_string()
+                };
+                format!(
+                    "{{\"event\":\"card_drawn\",\"player\":{},\"card\":{},\"face_up\":{}}}",
+                    player, code, card.face_up
+                )
+            }
+            GameEvent::War { players, pot_size } => format!(
+                "{{\"event\":\"war\",\"players\":{},\"pot_size\":{}}}",
+                json_list(players),
+                pot_size
+            ),
+            GameEvent::RoundWon {
+                round,
+                winner,
+                pot_size,
+            } => format!(
+                "{{\"event\":\"round_won\",\"round\":{},\"winner\":{},\"pot_size\":{}}}",
+                round, winner, pot_size
+            ),
+            GameEvent::GameOver {
+                winner,
+                rounds,
+                reason,
+            } => {
+                let winner = match winner {
+                    Some(player) => player.to_string(),
+                    None => "null".to_string(),
+                };
+                format!(
+                    "{{\"event\":\"game_over\",\"winner\":{},\"rounds\":{},\"reason\":\"{}\"}}",
+                    winner,
+                    rounds,
+                    reason_name(*reason)
+                )
+            }
+        }
+    }
+
+    /// Read back an event written by `to_log_json`
+    pub(crate) fn from_json(json: &Json) -> GameResult<GameEvent> {
+        let number = |key: &str| -> GameResult<usize> { small(json.field(key)?.as_u64()?) };
+        let numbers = |key: &str| -> GameResult<Vec<usize>> {
+            json.field(key)?
+                .as_array()?
+                .iter()
+                .map(|value| small(value.as_u64()?))
+                .collect()
+        };
+
+        Ok(match json.field("event")?.as_str()? {
+            "round_start" => GameEvent::RoundStart {
+                round: number("round")?,
+                card_counts: numbers("card_counts")?,
+            },
+            "card_drawn" => GameEvent::CardDrawn {
+                player: number("player")?,
+                card: PlayedCard {
+                    card: json.field("card")?.as_card()?,
+                    face_up: json.field("face_up")?.as_bool()?,
+                },
+            },
+            "war" => GameEvent::War {
+                players: numbers("players")?,
+                pot_size: number("pot_size")?,
+            },
+            "round_won" => GameEvent::RoundWon {
+                round: number("round")?,
+                winner: number("winner")?,
+                pot_size: number("pot_size")?,
+            },
+            "game_over" => GameEvent::GameOver {
+                winner: json
+                    .field("winner")?
+                    .as_option(|value| small(value.as_u64()?))?,
+                rounds: number("rounds")?,
+                reason: match json.field("reason")?.as_str()? {
+                    "out_of_cards" => EndReason::OutOfCards,
+                    "target_score" => EndReason::TargetScore,
+                    "truncated_by_limit" => EndReason::TruncatedByLimit,
+                    "cycle" => EndReason::Cycle,
+                    other => return Err(invalid(format!("unknown end reason \"{}\"", other))),
+                },
+            },
+            other => return Err(invalid(format!("unknown event \"{}\"", other))),
+        })
+    }
+}
+
+fn reason_name(reason: EndReason) -> &'static str {
+    match reason {
+        EndReason::OutOfCards => "out_of_cards",
+        EndReason::TargetScore => "target_score",
+        EndReason::TruncatedByLimit => "truncated_by_limit",
+        EndReason::Cycle => "cycle",
+    }
+}
+
+/// A line of plain narration, for replaying a recorded game
+impl fmt::Display for GameEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameEvent::RoundStart { round, card_counts } => {
+                let holdings: Vec<String> = card_counts
+                    .iter()
+                    .enumerate()
+                    .map(|(i, count)| format!("Player {} has {} cards", i + 1, count))
+                    .collect();
+                write!(f, "\n--- Round {} ---\n{}", round, holdings.join(", "))
+            }
+            GameEvent::CardDrawn { player, card } => {
+                write!(f, "🃏 Player {} draws: {}", player, card)
+            }
+            GameEvent::War { players, pot_size } => write!(
+                f,
+                "WAR! {} fight over {} cards",
+                player_list(players),
+                pot_size
+            ),
+            GameEvent::RoundWon {
+                round,
+                winner,
+                pot_size,
+            } => write!(
+                f,
+                "Player {} wins round {} and {} cards",
+                winner, round, pot_size
+            ),
+            GameEvent::GameOver {
+                winner,
+                rounds,
+                reason,
+            } => {
+                write!(f, "\n🎉 GAME OVER after {} rounds: ", rounds)?;
+                match (reason, winner) {
+                    (EndReason::Cycle, _) => write!(f, "the game loops forever, so it's a draw"),
+                    (EndReason::TruncatedByLimit, None) => {
+                        write!(f, "the round limit leaves it tied")
+                    }
+                    (EndReason::TruncatedByLimit, Some(winner)) => {
+                        write!(f, "Player {} leads at the round limit", winner)
+                    }
+                    (EndReason::TargetScore, winner) => {
+                        write!(f, "Player {} reaches the target score", winner.unwrap_or(0))
+                    }
+                    (EndReason::OutOfCards, winner) => {
+                        write!(f, "Player {} wins", winner.unwrap_or(0))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Prints each `GameEvent` to stdout as a JSON line; `WarGame`'s default
+/// observer for JSON output
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonObserver;
+
+impl JsonObserver {
+    fn emit(&self, event: GameEvent) {
+        println!("{}", event.to_json());
+    }
+}
+
+impl GameObserver for JsonObserver {
+    fn on_round_start(&mut self, round: usize, card_counts: &[usize]) {
+        self.emit(GameEvent::RoundStart {
+            round,
+            card_counts: card_counts.to_vec(),
+        });
+    }
+
+    fn on_card_drawn(&mut self, player: usize, card: PlayedCard) {
+        self.emit(GameEvent::CardDrawn { player, card });
+    }
+
+    fn on_card_burned(&mut self, player: usize, _number: usize, card: PlayedCard) {
+        self.emit(GameEvent::CardDrawn { player, card });
+    }
+
+    fn on_war(&mut self, players: &[usize], pot_size: usize) {
+        self.emit(GameEvent::War {
+            players: players.to_vec(),
+            pot_size,
+        });
+    }
+
+    fn on_round_won(&mut self, round: usize, winner: usize, pot_size: usize) {
+        self.emit(GameEvent::RoundWon {
+            round,
+            winner,
+            pot_size,
+        });
+    }
+
+    fn on_game_over(&mut self, outcome: &GameOutcome, _stats: &GameStats) {
+        self.emit(GameEvent::GameOver {
+            winner: outcome.winner,
+            rounds: outcome.rounds,
+            reason: outcome.reason,
+        });
+    }
+}
+
+/// `[1,2,3]`
+fn json_list(values: &[usize]) -> String {
+    let values: Vec<String> = values.iter().map(usize::to_string).collect();
+    format!("[{}]", values.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::{Card, Rank, Suit};
+
+    #[test]
+    fn test_events_render_as_tagged_json() {
+        let card = Card::new(Suit::Spades, Rank::Ten);
+        let cases = [
+            (
+                GameEvent::RoundStart {
+                    round: 3,
+                    card_counts: vec![30, 22],
+                },
+                r#"{"event":"round_start","round":3,"card_counts":[30,22]}"#,
+            ),
+            (
+                GameEvent::CardDrawn {
+                    player: 2,
+                    card: PlayedCard {
+                        card,
+                        face_up: true,
+                    },
+                },
+                r#"{"event":"card_drawn","player":2,"card":"10S","face_up":true}"#,
+            ),
+            (
+                GameEvent::CardDrawn {
+                    player: 1,
+                    card: PlayedCard {
+                        card,
+                        face_up: false,
+                    },
+                },
+                r#"{"event":"card_drawn","player":1,"card":null,"face_up":false}"#,
+            ),
+            (
+                GameEvent::War {
+                    players: vec![1, 3],
+                    pot_size: 3,
+                },
+                r#"{"event":"war","players":[1,3],"pot_size":3}"#,
+            ),
+            (
+                GameEvent::RoundWon {
+                    round: 3,
+                    winner: 1,
+                    pot_size: 10,
+                },
+                r#"{"event":"round_won","round":3,"winner":1,"pot_size":10}"#,
+            ),
+            (
+                GameEvent::GameOver {
+                    winner: None,
+                    rounds: 10000,
+                    reason: EndReason::TruncatedByLimit,
+                },
+                r#"{"event":"game_over","winner":null,"rounds":10000,"reason":"truncated_by_limit"}"#,
+            ),
+        ];
+
+        for (event, json) in cases {
+            assert_eq!(event.to_json(), json);
+        }
+    }
+}
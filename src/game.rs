@@ -0,0 +1,1476 @@
+use crate::cards::{Card, Deck, MAX_DECK_SIZE, MAX_JOKERS, PlayedCard, PotOrder, ValueTable};
+use crate::engine::{GameEngine, Pause, StepResult};
+use crate::events::{JsonObserver, OutputFormat};
+use crate::observer::{ConsoleObserver, GameObserver, NullObserver};
+use crate::ring_buffer::RingBuffer;
+use crate::save::SavedGame;
+use crate::stats::GameStats;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum GameError {
+    PlayerOutOfCards(usize),
+    InvalidPlayerNumber(usize),
+    BattleBufferFull,
+    InvalidConfig(String),
+    InvalidDeckSize(usize),
+    InvalidCard(String),
+    InvalidSave(String),
+    IoError(io::Error),
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::PlayerOutOfCards(player) => write!(f, "Player {} is out of cards", player),
+            GameError::InvalidPlayerNumber(player) => {
+                write!(f, "Invalid player number: {}", player)
+            }
+            GameError::BattleBufferFull => write!(f, "Battle buffer is full - cannot continue war"),
+            GameError::InvalidConfig(message) => write!(f, "Invalid configuration: {}", message),
+            GameError::InvalidDeckSize(count) => {
+                write!(
+                    f,
+                    "A deck needs exactly 52 cards plus up to {} Jokers, got {}",
+                    MAX_JOKERS, count
+                )
+            }
+            GameError::InvalidCard(message) => write!(f, "Invalid card: {}", message),
+            GameError::InvalidSave(message) => write!(f, "Invalid save file: {}", message),
+            GameError::IoError(e) => write!(f, "I/O error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for GameError {}
+
+impl From<io::Error> for GameError {
+    fn from(error: io::Error) -> Self {
+        GameError::IoError(error)
+    }
+}
+
+pub type GameResult<T> = Result<T, GameError>;
+
+/// Most players a `WarGame` can seat
+pub const MAX_PLAYERS: usize = 4;
+
+/// Rule and presentation options for a game
+#[derive(Debug, Clone, Copy)]
+pub struct GameConfig {
+    pub test_mode: bool,
+    pub interactive: bool,
+    pub hot_seat: bool,
+    /// Cards whose values differ by at most this much go to war
+    pub war_tolerance: u8,
+    /// Reveal the deciding war cards one character at a time
+    pub animate: bool,
+    /// End the game as soon as a player's chip score reaches this
+    pub target_score: Option<u32>,
+    /// House-rule card values used to decide rounds
+    pub values: ValueTable,
+    /// How a won pot is sequenced under the winner's hand
+    pub pot_order: PotOrder,
+    /// Show burned war cards instead of keeping them face down
+    pub show_burns: bool,
+    /// Deal-start offset: 0 gives player 1 the top card, 1 gives it to player 2
+    pub deal_offset: usize,
+    /// Round limit; `None` means 20 in test mode and `DEFAULT_MAX_ROUNDS`
+    /// otherwise (see `round_limit`)
+    /// The limit is checked between rounds, and a war (however many levels)
+    /// belongs to the round that started it, so a war in the last allowed
+    /// round is fought to the finish: the pot is always distributed before
+    /// the card counts decide the game
+    pub max_rounds: Option<usize>,
+    /// How many cards each player lays face down before a war's deciders
+    pub war_variant: WarVariant,
+    /// Players at the table, from 2 to `MAX_PLAYERS`; only `WarGame` and
+    /// `GameEngine` deal more than two, the headless `Simulation` seats a pair
+    pub players: usize,
+    /// Narrate in prose, or report `GameEvent`s as JSON lines
+    pub format: OutputFormat,
+    /// Narrate nothing, leaving the outcome `play` returns for the caller
+    /// to report (text output only)
+    pub quiet: bool,
+    /// End the game as a draw (`EndReason::Cycle`) as soon as the hands
+    /// repeat a position seen after an earlier round
+    /// Costs one 8-byte fingerprint per round in a hash set, roughly 16-20
+    /// bytes with table overhead, so about 200 KB at `DEFAULT_MAX_ROUNDS`.
+    /// Ignored when `target_score` is set, since growing chip scores end
+    /// a looping game anyway
+    pub detect_cycles: bool,
+    /// Shuffle each won pot before it goes under the winner's hand instead
+    /// of ordering it by `pot_order`; the shuffles are seeded from the deal,
+    /// so a seeded game still replays exactly
+    pub shuffle_winnings: bool,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        GameConfig {
+            test_mode: false,
+            interactive: false,
+            hot_seat: false,
+            war_tolerance: 0,
+            animate: false,
+            target_score: None,
+            values: ValueTable::default(),
+            pot_order: PotOrder::default(),
+            show_burns: false,
+            deal_offset: 0,
+            max_rounds: None,
+            war_variant: WarVariant::default(),
+            players: 2,
+            format: OutputFormat::Text,
+            quiet: false,
+            detect_cycles: false,
+            shuffle_winnings: false,
+        }
+    }
+}
+
+/// The war rule used when a pair of face-up cards ties
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WarVariant {
+    /// Three cards face down, then one face up
+    #[default]
+    BurnThree,
+    /// One card face down, then one face up
+    OneDownOneUp,
+}
+
+impl WarVariant {
+    /// Face-down cards each player burns per war level
+    pub fn burns(self) -> usize {
+        match self {
+            WarVariant::BurnThree => 3,
+            WarVariant::OneDownOneUp => 1,
+        }
+    }
+}
+
+/// How a pair of face-up cards is decided
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundResult {
+    /// The player (1 or 2) whose card wins outright
+    Win(usize),
+    /// The values are close enough (by default, equal) to go to war
+    War,
+}
+
+/// Decide a pair of face-up cards, `card1` belonging to player 1
+/// Only values count (via `config.values`); suits never break ties
+/// When the deciding cards of a war tie again, the game fights another war
+/// on top of the pot, until one player wins or runs out of cards (forfeiting
+/// the pot and the game)
+pub fn resolve_round(card1: Card, card2: Card, config: &GameConfig) -> RoundResult {
+    let (value1, value2) = (
+        card1.value_with(&config.values),
+        card2.value_with(&config.values),
+    );
+    if value1.abs_diff(value2) <= config.war_tolerance {
+        RoundResult::War
+    } else if value1 > value2 {
+        RoundResult::Win(1)
+    } else {
+        RoundResult::Win(2)
+    }
+}
+
+/// Up to one entry per seat, held inline so that playing a round never
+/// allocates; derefs to the entries as a slice, in seat order
+/// Entries are only ever pushed or removed, never popped off the front, so
+/// the ring buffer underneath stays contiguous
+#[derive(Clone, PartialEq, Eq)]
+pub struct Seats<T>(RingBuffer<T, MAX_PLAYERS>);
+
+impl<T> Seats<T> {
+    pub fn new() -> Self {
+        Seats(RingBuffer::new())
+    }
+
+    /// Add an entry after the others; panics past `MAX_PLAYERS` entries
+    pub fn push(&mut self, item: T) {
+        assert!(
+            self.0.push_back(item),
+            "more than {} seats at the table",
+            MAX_PLAYERS
+        );
+    }
+
+    /// Take out the entry at `index`, closing the gap
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        self.0.remove(index)
+    }
+}
+
+impl<T> Default for Seats<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for Seats<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        let (seats, wrapped) = self.0.as_slices();
+        debug_assert!(wrapped.is_empty());
+        seats
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Seats<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> FromIterator<T> for Seats<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut seats = Seats::new();
+        for item in iter {
+            seats.push(item);
+        }
+        seats
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Seats<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Decide a trick of face-up cards, one `(player, card)` per player
+/// The highest value wins, and every card within `config.war_tolerance` of
+/// it goes to war; returns the players still in contention in trick order,
+/// so a lone entry is the outright winner
+/// For a pair of cards this agrees with `resolve_round`
+pub fn resolve_trick(played: &[(usize, Card)], config: &GameConfig) -> Seats<usize> {
+    let value = |card: &Card| card.value_with(&config.values);
+    let Some(best) = played.iter().map(|(_, card)| value(card)).max() else {
+        return Seats::new();
+    };
+    played
+        .iter()
+        .filter(|(_, card)| best - value(card) <= config.war_tolerance)
+        .map(|&(player, _)| player)
+        .collect()
+}
+
+impl GameConfig {
+    /// The round after which the game is decided by card count:
+    /// `max_rounds` if set, otherwise 20 in test mode and
+    /// `DEFAULT_MAX_ROUNDS` outside it
+    pub fn round_limit(&self) -> usize {
+        self.max_rounds.unwrap_or(if self.test_mode {
+            20
+        } else {
+            DEFAULT_MAX_ROUNDS
+        })
+    }
+
+    /// How a burned war card appears on the table
+    pub(crate) fn burn(&self, card: Card) -> PlayedCard {
+        PlayedCard {
+            card,
+            face_up: self.show_burns,
+        }
+    }
+}
+
+/// Round limit after which a game is decided by card count
+pub const DEFAULT_MAX_ROUNDS: usize = 10000;
+
+/// Why a finished game stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndReason {
+    /// A player ran out of cards
+    OutOfCards,
+    /// A player's chip score reached the target
+    TargetScore,
+    /// The round limit cut the game short; the winner only leads on card
+    /// count, so the result is provisional
+    TruncatedByLimit,
+    /// Every hand repeated an earlier position, so play would loop forever;
+    /// the game is a draw (see `GameConfig::detect_cycles`)
+    Cycle,
+}
+
+/// How and when a finished game ended
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameOutcome {
+    /// `None` for a draw: equal card counts at the round limit, or a cycle
+    pub winner: Option<usize>,
+    pub rounds: usize,
+    pub reason: EndReason,
+    /// Cards each player ended with, player 1 first
+    pub final_counts: Seats<usize>,
+}
+
+/// A one-line result, e.g. "Player 2 wins after 343 rounds"
+impl fmt::Display for GameOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.reason, self.winner) {
+            (EndReason::TruncatedByLimit, winner) => {
+                write!(f, "Round limit reached after {} rounds: ", self.rounds)?;
+                match winner {
+                    Some(player) => write!(f, "Player {} leads", player)?,
+                    None => write!(f, "tied")?,
+                }
+                let counts: Vec<String> = self.final_counts.iter().map(usize::to_string).collect();
+                write!(f, " {}", counts.join(" to "))
+            }
+            (EndReason::Cycle, _) => {
+                write!(
+                    f,
+                    "Draw after {} rounds: the game loops forever",
+                    self.rounds
+                )
+            }
+            (EndReason::TargetScore, winner) => write!(
+                f,
+                "Player {} reaches the target score after {} rounds",
+                winner.unwrap_or(0),
+                self.rounds
+            ),
+            (EndReason::OutOfCards, winner) => write!(
+                f,
+                "Player {} wins after {} rounds",
+                winner.unwrap_or(0),
+                self.rounds
+            ),
+        }
+    }
+}
+
+/// A game of War for two to `MAX_PLAYERS` players, played at the terminal:
+/// a `GameEngine` plus keypress prompts and a `GameObserver`
+/// The engine only decides rounds; the observer does the narrating,
+/// which by default is a `ConsoleObserver` (a `NullObserver` when
+/// `config.quiet` is set, or a `JsonObserver` when `config.format` asks for
+/// JSON). Only keypress prompts are printed directly; `play` returns the
+/// outcome for the caller to act on
+/// Cloning copies the whole game state (it all lives inline) so a branch can
+/// be played out without disturbing the original; see `branch` for games
+/// whose input can't be cloned, such as stdin
+/// An installed observer belongs to the original, not the game state, so
+/// clones and branches start with the default observer for their config,
+/// and don't autosave
+pub struct WarGame<R: Read = io::Stdin, const POT: usize = MAX_DECK_SIZE> {
+    engine: GameEngine<POT>,
+    input: R,
+    observer: Box<dyn GameObserver>,
+    /// Where `play` saves the game after every round, if anywhere
+    autosave: Option<PathBuf>,
+}
+
+/// The observer that narrates a game in `config.format`, which `WarGame`
+/// installs unless told otherwise
+pub fn default_observer(config: &GameConfig) -> Box<dyn GameObserver> {
+    match config.format {
+        OutputFormat::Text if config.quiet => Box::new(NullObserver),
+        OutputFormat::Text => Box::new(ConsoleObserver::new(*config)),
+        OutputFormat::Json => Box::new(JsonObserver),
+    }
+}
+
+impl<R: Read + Clone, const POT: usize> Clone for WarGame<R, POT> {
+    fn clone(&self) -> Self {
+        self.branch(self.input.clone())
+    }
+}
+
+// Keypresses come from the terminal, which doesn't exist on wasm32
+#[cfg(not(target_arch = "wasm32"))]
+impl WarGame {
+    pub fn new(config: GameConfig) -> Self {
+        let mut deck = Deck::new();
+        deck.shuffle();
+        WarGame::from_deck(deck, config, io::stdin())
+    }
+
+    pub fn new_with_seed(config: GameConfig, seed: u64) -> Self {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(seed);
+        WarGame::from_deck(deck, config, io::stdin())
+    }
+}
+
+impl<R: Read> WarGame<R> {
+    /// Deal a prepared deck, reading keypresses from the given input
+    pub fn from_deck(deck: Deck, config: GameConfig, input: R) -> Self {
+        Self::from_deck_with_pot(deck, config, input)
+    }
+}
+
+impl<R: Read, const POT: usize> WarGame<R, POT> {
+    /// Like `from_deck`, with a pot of any capacity, e.g.
+    /// `WarGame::<_, 104>::from_deck_with_pot(deck, config, input)`
+    /// Panics if `config.players` is outside `2..=MAX_PLAYERS`
+    pub fn from_deck_with_pot(deck: Deck, config: GameConfig, input: R) -> Self {
+        Self::from_engine(GameEngine::from_deck_with_pot(deck, config), input)
+    }
+
+    /// Play an engine at the terminal, narrated by the default observer
+    /// for its config
+    pub fn from_engine(engine: GameEngine<POT>, input: R) -> Self {
+        let observer = default_observer(&engine.config);
+        WarGame {
+            engine,
+            input,
+            observer,
+            autosave: None,
+        }
+    }
+
+    /// Pick up a saved game, playing by the rules it was saved with and
+    /// presenting it (prompts, animation, output format) as `config` asks
+    /// (see `GameEngine::resume`)
+    pub fn resume(saved: &SavedGame, config: GameConfig, input: R) -> GameResult<Self> {
+        let mut engine = GameEngine::resume(saved)?;
+        engine.config = GameConfig {
+            interactive: config.interactive,
+            hot_seat: config.hot_seat,
+            animate: config.animate,
+            format: config.format,
+            quiet: config.quiet,
+            ..engine.config
+        };
+        Ok(Self::from_engine(engine, input))
+    }
+
+    /// `resume` a game written by `save_to`
+    pub fn load_from(path: &Path, config: GameConfig, input: R) -> GameResult<Self> {
+        let saved = SavedGame::from_json(&std::fs::read_to_string(path)?)?;
+        Self::resume(&saved, config, input)
+    }
+
+    /// The game as it stands between rounds, for `resume`
+    pub fn saved(&self) -> SavedGame {
+        self.engine.saved()
+    }
+
+    /// Write the game to `path` as JSON, for `load_from`
+    pub fn save_to(&self, path: &Path) -> GameResult<()> {
+        std::fs::write(path, self.saved().to_json())?;
+        Ok(())
+    }
+
+    /// Have `play` save the game to `path` after every round, so an
+    /// interrupted game can be picked up again with `load_from`
+    pub fn autosave_to(&mut self, path: PathBuf) {
+        self.autosave = Some(path);
+    }
+
+    /// The engine underneath, for inspecting the game state
+    pub fn engine(&self) -> &GameEngine<POT> {
+        &self.engine
+    }
+
+    #[cfg(test)]
+    fn play_round(&mut self) -> GameResult<Option<usize>> {
+        let WarGame {
+            engine,
+            input,
+            observer,
+            ..
+        } = self;
+        let config = engine.config;
+        engine.play_round(observer.as_mut(), &mut |pause| {
+            prompt(input, &config, pause)
+        })
+    }
+
+    /// Copy the game state onto a different input source
+    pub fn branch<I: Read>(&self, input: I) -> WarGame<I, POT> {
+        WarGame::from_engine(self.engine.clone(), input)
+    }
+
+    /// Report game events to `observer` instead of the default narration
+    pub fn set_observer<O: GameObserver + 'static>(&mut self, observer: O) {
+        self.observer = Box::new(observer);
+    }
+
+    /// Returns true once `play` has run the game to completion
+    pub fn is_finished(&self) -> bool {
+        self.engine.is_finished()
+    }
+
+    /// How the game ended, once `play` has finished it
+    pub fn outcome(&self) -> Option<GameOutcome> {
+        self.engine.outcome()
+    }
+
+    /// The winning player once the game has finished, `None` while it is in progress
+    /// If the round limit ends the game, the player holding the most cards
+    /// wins (still `None` when the lead is shared)
+    pub fn winner(&self) -> Option<usize> {
+        self.engine.winner()
+    }
+
+    /// Statistics gathered so far
+    pub fn stats(&self) -> &GameStats {
+        self.engine.stats()
+    }
+
+    /// Play the game to the end and report how it finished
+    pub fn play(&mut self) -> GameResult<GameOutcome> {
+        self.observer.on_game_start(&self.engine.card_counts());
+
+        loop {
+            let WarGame {
+                engine,
+                input,
+                observer,
+                ..
+            } = self;
+            let config = engine.config;
+            let step = engine.step_with(observer.as_mut(), &mut |pause| {
+                prompt(input, &config, pause)
+            })?;
+            match step {
+                StepResult::Played => {
+                    if let Some(path) = &self.autosave {
+                        self.save_to(path)?;
+                    }
+                }
+                StepResult::Finished(outcome) => return Ok(outcome),
+            }
+        }
+    }
+}
+
+/// Wait for SPACE wherever `config` asks for a keypress
+fn prompt<R: Read>(input: &mut R, config: &GameConfig, pause: Pause) -> GameResult<()> {
+    match pause {
+        Pause::Reveal(player) if config.hot_seat => read_space(
+            input,
+            &format!("Player {}, press SPACE to reveal your card...", player),
+        ),
+        Pause::Continue if config.interactive => read_space(input, "Press SPACE to continue..."),
+        _ => Ok(()),
+    }
+}
+
+fn read_space<R: Read>(input: &mut R, prompt: &str) -> GameResult<()> {
+    print!("{}", prompt);
+    io::stdout().flush()?;
+
+    let mut buffer = [0; 1];
+    loop {
+        match input.read_exact(&mut buffer) {
+            Ok(_) => {
+                if buffer[0] == b' ' {
+                    break;
+                }
+            }
+            Err(e) => return Err(GameError::IoError(e)),
+        }
+    }
+    println!(); // New line after space is pressed
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::{PlayerHand, Rank, Suit};
+
+    #[test]
+    fn test_hot_seat_reveals_both_cards_and_resolves_round() {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(42);
+        // One SPACE per player, with a stray key before the second reveal
+        let input: &[u8] = b" x ";
+        let config = GameConfig {
+            hot_seat: true,
+            ..GameConfig::default()
+        };
+        let mut game = WarGame::from_deck(deck, config, input);
+
+        assert_eq!(game.play_round().unwrap(), None);
+
+        // Both reveals consumed their keypresses
+        assert!(game.input.is_empty());
+        assert_eq!(game.engine.round, 1);
+        assert!(game.engine.battle_buffer.is_empty());
+        assert_eq!(game.engine.hands[0].len() + game.engine.hands[1].len(), 52);
+        assert_ne!(game.engine.hands[0].len(), game.engine.hands[1].len());
+    }
+
+    /// Stack a hand so cards are drawn in the listed order
+    fn hand_of(cards: &[Card]) -> PlayerHand {
+        let mut hand = PlayerHand::new();
+        for &card in cards.iter().rev() {
+            hand.add_card(card);
+        }
+        hand
+    }
+
+    /// A game dealt exactly the given hands
+    fn game_with_hands(
+        config: GameConfig,
+        player1: &[Card],
+        player2: &[Card],
+    ) -> WarGame<io::Empty> {
+        game_with_table(config, &[player1, player2])
+    }
+
+    /// A game of `config.players` players, dealt exactly the given hands
+    fn game_with_table(config: GameConfig, hands: &[&[Card]]) -> WarGame<io::Empty> {
+        let mut game = WarGame::from_deck(Deck::new(), config, io::empty());
+        for (seat, cards) in game.engine.hands.iter_mut().zip(hands) {
+            *seat = hand_of(cards);
+        }
+        game.engine.deck_size = hands.iter().map(|cards| cards.len()).sum();
+        game
+    }
+
+    #[test]
+    fn test_war_tolerance_turns_near_tie_into_war() {
+        let config = GameConfig {
+            war_tolerance: 1,
+            ..GameConfig::default()
+        };
+        // King vs Queen, three burns each, then Ace vs Two decides the war
+        let mut game = game_with_hands(
+            config,
+            &[
+                Card::new(Suit::Hearts, Rank::King),
+                Card::new(Suit::Hearts, Rank::Three),
+                Card::new(Suit::Hearts, Rank::Four),
+                Card::new(Suit::Hearts, Rank::Five),
+                Card::new(Suit::Hearts, Rank::Ace),
+            ],
+            &[
+                Card::new(Suit::Spades, Rank::Queen),
+                Card::new(Suit::Spades, Rank::Three),
+                Card::new(Suit::Spades, Rank::Four),
+                Card::new(Suit::Spades, Rank::Five),
+                Card::new(Suit::Spades, Rank::Two),
+            ],
+        );
+
+        assert_eq!(game.stats().biggest_war, 0);
+        // Player 2 is left with nothing, so the war ends the game
+        assert_eq!(game.play_round().unwrap(), Some(1));
+
+        // A plain King win would only have moved two cards
+        assert_eq!(game.stats().biggest_war, 10);
+        assert_eq!(game.engine.hands[0].len(), 10);
+        assert!(game.engine.hands[1].is_empty());
+    }
+
+    #[test]
+    fn test_default_tolerance_keeps_exact_ties_only() {
+        let config = GameConfig::default();
+        let king = Card::new(Suit::Hearts, Rank::King);
+        let queen = Card::new(Suit::Spades, Rank::Queen);
+
+        assert_eq!(resolve_round(king, queen, &config), RoundResult::Win(1));
+        assert_eq!(resolve_round(queen, king, &config), RoundResult::Win(2));
+        assert_eq!(resolve_round(king, king, &config), RoundResult::War);
+    }
+
+    #[test]
+    fn test_resolve_round_every_rank_pairing() {
+        let config = GameConfig::default();
+        for rank1 in Rank::all() {
+            for rank2 in Rank::all() {
+                // Different suits, to show they never matter
+                let card1 = Card::new(Suit::Spades, rank1);
+                let card2 = Card::new(Suit::Hearts, rank2);
+                let expected = match rank1.cmp(&rank2) {
+                    std::cmp::Ordering::Greater => RoundResult::Win(1),
+                    std::cmp::Ordering::Less => RoundResult::Win(2),
+                    std::cmp::Ordering::Equal => RoundResult::War,
+                };
+                assert_eq!(resolve_round(card1, card2, &config), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_winner_is_set_only_after_completion() {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(7);
+        let mut game = WarGame::from_deck(deck, GameConfig::default(), io::empty());
+
+        for _ in 0..5 {
+            assert_eq!(game.play_round().unwrap(), None);
+        }
+        assert!(!game.is_finished());
+        assert_eq!(game.winner(), None);
+
+        game.play().unwrap();
+
+        assert!(game.is_finished());
+        let winner = game.winner().expect("seed 7 should not end tied");
+        let (len1, len2) = (game.engine.hands[0].len(), game.engine.hands[1].len());
+        if winner == 1 {
+            assert!(len1 > len2);
+        } else {
+            assert!(len2 > len1);
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "card count drifted")]
+    fn test_lost_card_trips_card_count_assertion() {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(3);
+        let mut game = WarGame::from_deck(deck, GameConfig::default(), io::empty());
+
+        // Simulate a bug that drops a card on the floor
+        game.engine.hands[0].draw_card();
+        game.play_round().unwrap();
+    }
+
+    #[test]
+    fn test_reaching_target_score_ends_game() {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(11);
+        let config = GameConfig {
+            target_score: Some(20),
+            ..GameConfig::default()
+        };
+        let mut game = WarGame::from_deck(deck, config, io::empty());
+
+        game.play().unwrap();
+
+        let winner = game.winner().unwrap();
+        let loser = 3 - winner;
+        assert!(game.stats().score(winner) >= 20);
+        assert!(game.stats().score(loser) < 20);
+        // Both hands still hold cards, so the score is what ended it
+        assert!(!game.engine.hands[0].is_empty() && !game.engine.hands[1].is_empty());
+    }
+
+    #[test]
+    fn test_value_table_lets_two_beat_ace() {
+        let config = GameConfig {
+            values: ValueTable::identity().with(Rank::Two, 99),
+            ..GameConfig::default()
+        };
+        let mut game = game_with_hands(
+            config,
+            &[
+                Card::new(Suit::Hearts, Rank::Two),
+                Card::new(Suit::Hearts, Rank::Five),
+            ],
+            &[
+                Card::new(Suit::Spades, Rank::Ace),
+                Card::new(Suit::Spades, Rank::Six),
+            ],
+        );
+
+        assert_eq!(game.play_round().unwrap(), None);
+        assert_eq!(game.engine.hands[0].len(), 3);
+        assert_eq!(game.engine.hands[1].len(), 1);
+    }
+
+    #[test]
+    fn test_decided_at_round_falls_within_the_game() {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(42);
+        let mut game = WarGame::from_deck(deck, GameConfig::default(), io::empty());
+
+        game.play().unwrap();
+
+        let winner = game.winner().expect("seed 42 has a winner");
+        let decided = game.stats().decided_at_round(winner).unwrap();
+        assert!((1..=game.engine.round).contains(&decided));
+        // Seed 42 includes a double war: 2 + 8 + 8 cards
+        assert_eq!(game.stats().biggest_war, 18);
+    }
+
+    #[test]
+    fn test_burns_render_face_down_unless_shown() {
+        let ace = Card::new(Suit::Hearts, Rank::Ace);
+
+        assert_eq!(GameConfig::default().burn(ace).to_string(), "??");
+        let decider = PlayedCard {
+            card: ace,
+            face_up: true,
+        };
+        assert_eq!(decider.to_string(), "A♥");
+
+        let config = GameConfig {
+            show_burns: true,
+            ..GameConfig::default()
+        };
+        assert_eq!(config.burn(ace).to_string(), "A♥");
+    }
+
+    #[test]
+    fn test_stepping_a_clone_leaves_the_original_alone() {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(9);
+        let original = WarGame::from_deck(deck, GameConfig::default(), io::empty());
+        let hands = (
+            original.engine.hands[0].len(),
+            original.engine.hands[1].len(),
+        );
+
+        let mut branch = original.clone();
+        for _ in 0..5 {
+            branch.play_round().unwrap();
+        }
+
+        assert_eq!(branch.engine.round, 5);
+        assert_eq!(original.engine.round, 0);
+        assert_eq!(
+            (
+                original.engine.hands[0].len(),
+                original.engine.hands[1].len()
+            ),
+            hands
+        );
+        assert_eq!(original.stats(), &GameStats::default());
+
+        let mut from_stdin = WarGame::from_deck(Deck::new(), GameConfig::default(), io::stdin());
+        from_stdin.engine.round = 3;
+        assert_eq!(from_stdin.branch(io::empty()).engine.round, 3);
+    }
+
+    #[test]
+    fn test_longest_streak_matches_round_by_round_count() {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(42);
+        let mut game = WarGame::from_deck(deck, GameConfig::default(), io::empty());
+
+        let (mut longest, mut run, mut last_winner) = (0, 0, 0);
+        while game.engine.round < 300 {
+            let before = game.stats().scores;
+            if game.play_round().unwrap().is_some() {
+                break;
+            }
+            // Whoever's chip score grew took this round's pot
+            let winner = if game.stats().scores[0] > before[0] {
+                1
+            } else {
+                2
+            };
+            run = if winner == last_winner { run + 1 } else { 1 };
+            last_winner = winner;
+            longest = longest.max(run);
+        }
+
+        assert!(longest >= 2);
+        assert_eq!(game.stats().longest_streak, longest);
+    }
+
+    #[test]
+    fn test_round_limit_is_reported_as_truncation() {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(42);
+        let config = GameConfig {
+            max_rounds: Some(5),
+            ..GameConfig::default()
+        };
+        let mut game = WarGame::from_deck(deck, config, io::empty());
+        assert_eq!(game.outcome(), None);
+
+        game.play().unwrap();
+
+        let outcome = game.outcome().unwrap();
+        assert_eq!(outcome.reason, EndReason::TruncatedByLimit);
+        assert_eq!(outcome.rounds, 5);
+        assert_eq!(outcome.winner, game.winner());
+    }
+
+    #[test]
+    fn test_tied_deciders_start_another_war() {
+        let burns = |suit| [Rank::Three, Rank::Four, Rank::Six].map(|rank| Card::new(suit, rank));
+        let [h3, h4, h6] = burns(Suit::Hearts);
+        let [s3, s4, s6] = burns(Suit::Spades);
+        let [c3, c4, c6] = burns(Suit::Clubs);
+        let [d3, d4, d6] = burns(Suit::Diamonds);
+        // King vs King, then Five vs Five, then Ace vs Two
+        let mut game = game_with_hands(
+            GameConfig::default(),
+            &[
+                Card::new(Suit::Hearts, Rank::King),
+                h3,
+                h4,
+                h6,
+                Card::new(Suit::Hearts, Rank::Five),
+                c3,
+                c4,
+                c6,
+                Card::new(Suit::Hearts, Rank::Ace),
+                Card::new(Suit::Hearts, Rank::Nine),
+            ],
+            &[
+                Card::new(Suit::Spades, Rank::King),
+                s3,
+                s4,
+                s6,
+                Card::new(Suit::Spades, Rank::Five),
+                d3,
+                d4,
+                d6,
+                Card::new(Suit::Spades, Rank::Two),
+                Card::new(Suit::Spades, Rank::Nine),
+            ],
+        );
+
+        assert_eq!(game.play_round().unwrap(), None);
+
+        assert_eq!(game.engine.hands[0].len(), 19);
+        assert_eq!(game.engine.hands[1].len(), 1);
+        assert!(game.engine.battle_buffer.is_empty());
+        assert_eq!(game.stats().biggest_war, 18);
+        assert_eq!(game.stats().longest_war, 2);
+        assert_eq!(game.stats().cards_exchanged, 9);
+    }
+
+    #[test]
+    fn test_stacked_deck_starts_with_a_war() {
+        let mut fresh = Deck::new();
+        let mut cards: [Card; 52] = std::array::from_fn(|_| fresh.draw().unwrap());
+        // Each hand's top card is the last one dealt to it, so the first
+        // round is decided by the bottom two cards of the deck
+        for (slot, suit) in [(50, Suit::Hearts), (51, Suit::Spades)] {
+            let ace = Card::new(suit, Rank::Ace);
+            let at = cards.iter().position(|&card| card == ace).unwrap();
+            cards.swap(at, slot);
+        }
+        let deck = Deck::from_cards(cards).unwrap();
+        let mut game = WarGame::from_deck(deck, GameConfig::default(), io::empty());
+
+        game.play_round().unwrap();
+        assert_eq!(game.stats().wars, 1);
+    }
+
+    #[test]
+    fn test_running_out_mid_war_forfeits_the_pot() {
+        let mut game = game_with_hands(
+            GameConfig::default(),
+            &[
+                Card::new(Suit::Hearts, Rank::King),
+                Card::new(Suit::Hearts, Rank::Three),
+            ],
+            &[
+                Card::new(Suit::Spades, Rank::King),
+                Card::new(Suit::Spades, Rank::Four),
+                Card::new(Suit::Spades, Rank::Five),
+                Card::new(Suit::Spades, Rank::Six),
+            ],
+        );
+
+        assert_eq!(game.play_round().unwrap(), Some(2));
+        assert!(game.engine.hands[0].is_empty());
+        assert_eq!(game.engine.hands[1].len(), 6);
+    }
+
+    /// Hands stacked so six deciders in a row tie, then player 1's Ace takes
+    /// a 50-card pot
+    fn deep_war_hands() -> (Vec<Card>, Vec<Card>) {
+        let hand = |suit, last| {
+            let mut cards = Vec::new();
+            for value in 2..8 {
+                cards.push(Card::new(suit, Rank::from_value(value).unwrap()));
+                cards.extend([Card::new(suit, Rank::Ten); 3]);
+            }
+            cards.push(Card::new(suit, last));
+            cards.push(Card::new(suit, Rank::Jack));
+            cards
+        };
+        (
+            hand(Suit::Hearts, Rank::Ace),
+            hand(Suit::Spades, Rank::King),
+        )
+    }
+
+    #[test]
+    fn test_deeply_nested_war_keeps_every_card() {
+        let (player1, player2) = deep_war_hands();
+        assert_eq!(player1.len() + player2.len(), 52);
+        let mut game = game_with_hands(GameConfig::default(), &player1, &player2);
+
+        assert_eq!(game.play_round().unwrap(), None);
+
+        assert_eq!(game.engine.hands[0].len(), 51);
+        assert_eq!(game.engine.hands[1].len(), 1);
+        assert_eq!(game.stats().biggest_war, 50);
+    }
+
+    #[test]
+    fn test_one_down_one_up_war_burns_a_single_card_per_level() {
+        let config = GameConfig {
+            war_variant: WarVariant::OneDownOneUp,
+            ..GameConfig::default()
+        };
+        // King vs King, one down, Five vs Five, one down, Ace vs Two
+        let mut game = game_with_hands(
+            config,
+            &[
+                Card::new(Suit::Hearts, Rank::King),
+                Card::new(Suit::Hearts, Rank::Three),
+                Card::new(Suit::Hearts, Rank::Five),
+                Card::new(Suit::Hearts, Rank::Four),
+                Card::new(Suit::Hearts, Rank::Ace),
+                Card::new(Suit::Hearts, Rank::Nine),
+            ],
+            &[
+                Card::new(Suit::Spades, Rank::King),
+                Card::new(Suit::Spades, Rank::Three),
+                Card::new(Suit::Spades, Rank::Five),
+                Card::new(Suit::Spades, Rank::Four),
+                Card::new(Suit::Spades, Rank::Two),
+                Card::new(Suit::Spades, Rank::Nine),
+            ],
+        );
+
+        assert_eq!(game.play_round().unwrap(), None);
+
+        assert_eq!(game.stats().biggest_war, 10);
+        assert_eq!(game.engine.hands[0].len(), 11);
+        assert_eq!(game.engine.hands[1].len(), 1);
+    }
+
+    #[test]
+    fn test_pot_capacity_is_configurable() {
+        let (player1, player2) = deep_war_hands();
+        let mut small =
+            WarGame::<_, 16>::from_deck_with_pot(Deck::new(), GameConfig::default(), io::empty());
+        small.engine.hands[0] = hand_of(&player1);
+        small.engine.hands[1] = hand_of(&player2);
+
+        assert!(matches!(
+            small.play_round(),
+            Err(GameError::BattleBufferFull)
+        ));
+
+        let mut large =
+            WarGame::<_, 104>::from_deck_with_pot(Deck::new(), GameConfig::default(), io::empty());
+        large.engine.hands[0] = hand_of(&player1);
+        large.engine.hands[1] = hand_of(&player2);
+
+        assert_eq!(large.play_round().unwrap(), None);
+        assert_eq!(large.engine.hands[0].len(), 51);
+    }
+
+    #[test]
+    fn test_war_in_the_last_round_is_finished_before_the_limit() {
+        let (player1, player2) = deep_war_hands();
+        let config = GameConfig {
+            max_rounds: Some(1),
+            ..GameConfig::default()
+        };
+        let mut game = game_with_hands(config, &player1, &player2);
+
+        game.play().unwrap();
+
+        let outcome = game.outcome().unwrap();
+        assert_eq!(outcome.reason, EndReason::TruncatedByLimit);
+        assert_eq!(outcome.rounds, 1);
+        assert_eq!(outcome.winner, Some(1));
+        // The whole six-level pot went to the war's winner
+        assert!(game.engine.battle_buffer.is_empty());
+        assert_eq!(game.engine.hands[0].len(), 51);
+        assert_eq!(game.engine.hands[1].len(), 1);
+    }
+
+    #[test]
+    fn test_observer_sees_every_pot_won() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        type Pot = (usize, Vec<Card>);
+        #[derive(Default)]
+        struct Pots(Rc<RefCell<Vec<Pot>>>);
+        impl GameObserver for Pots {
+            fn on_pot_won(&mut self, winner: usize, cards: &[Card]) {
+                self.0.borrow_mut().push((winner, cards.to_vec()));
+            }
+        }
+
+        let (player1, player2) = deep_war_hands();
+        let mut game = game_with_hands(GameConfig::default(), &player1, &player2);
+        let pots = Pots::default();
+        let seen = Rc::clone(&pots.0);
+        game.set_observer(pots);
+
+        game.play_round().unwrap();
+
+        // The pot is reported as played: player 1's card first in each pair
+        let played: Vec<Card> = player1[..25]
+            .iter()
+            .zip(&player2[..25])
+            .flat_map(|(&card1, &card2)| [card1, card2])
+            .collect();
+        assert_eq!(*seen.borrow(), vec![(1, played)]);
+
+        // A clone narrates by default instead of reporting to the original's observer
+        game.clone().play_round().unwrap();
+        assert_eq!(seen.borrow().len(), 1);
+    }
+
+    #[test]
+    fn test_recording_observer_sees_a_seeded_game() {
+        use crate::events::GameEvent;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        #[derive(Default)]
+        struct Recorder(Rc<RefCell<Vec<GameEvent>>>);
+        impl GameObserver for Recorder {
+            fn on_round_start(&mut self, round: usize, card_counts: &[usize]) {
+                self.0.borrow_mut().push(GameEvent::RoundStart {
+                    round,
+                    card_counts: card_counts.to_vec(),
+                });
+            }
+            fn on_card_drawn(&mut self, player: usize, card: PlayedCard) {
+                self.0
+                    .borrow_mut()
+                    .push(GameEvent::CardDrawn { player, card });
+            }
+            fn on_war(&mut self, players: &[usize], pot_size: usize) {
+                self.0.borrow_mut().push(GameEvent::War {
+                    players: players.to_vec(),
+                    pot_size,
+                });
+            }
+            fn on_round_won(&mut self, round: usize, winner: usize, pot_size: usize) {
+                self.0.borrow_mut().push(GameEvent::RoundWon {
+                    round,
+                    winner,
+                    pot_size,
+                });
+            }
+            fn on_game_over(&mut self, outcome: &GameOutcome, _: &GameStats) {
+                self.0.borrow_mut().push(GameEvent::GameOver {
+                    winner: outcome.winner,
+                    rounds: outcome.rounds,
+                    reason: outcome.reason,
+                });
+            }
+        }
+
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(3);
+        let mut game = WarGame::from_deck(deck, GameConfig::default(), io::empty());
+        let recorder = Recorder::default();
+        let events = Rc::clone(&recorder.0);
+        game.set_observer(recorder);
+        game.play().unwrap();
+
+        let events = events.borrow();
+        let face_up = |player, code: &str| GameEvent::CardDrawn {
+            player,
+            card: PlayedCard {
+                card: code.parse().unwrap(),
+                face_up: true,
+            },
+        };
+        assert_eq!(
+            events[..4],
+            [
+                GameEvent::RoundStart {
+                    round: 1,
+                    card_counts: vec![26, 26],
+                },
+                face_up(1, "9D"),
+                face_up(2, "7D"),
+                GameEvent::RoundWon {
+                    round: 1,
+                    winner: 1,
+                    pot_size: 2,
+                },
+            ]
+        );
+        assert_eq!(
+            events.last(),
+            Some(&GameEvent::GameOver {
+                winner: Some(2),
+                rounds: 343,
+                reason: EndReason::OutOfCards,
+            })
+        );
+        let won = events
+            .iter()
+            .filter(|event| matches!(event, GameEvent::RoundWon { .. }))
+            .count();
+        let wars = events
+            .iter()
+            .filter(|event| matches!(event, GameEvent::War { .. }))
+            .count();
+        assert_eq!(won as u64, game.stats().rounds);
+        assert!(wars as u64 >= game.stats().wars);
+    }
+
+    #[test]
+    fn test_only_tied_players_go_to_war() {
+        let card = Card::new;
+        let config = GameConfig {
+            players: 3,
+            ..GameConfig::default()
+        };
+        // King vs King vs Five, then Ace vs Two between players 1 and 2
+        let mut game = game_with_table(
+            config,
+            &[
+                &[
+                    card(Suit::Hearts, Rank::King),
+                    card(Suit::Hearts, Rank::Three),
+                    card(Suit::Hearts, Rank::Four),
+                    card(Suit::Hearts, Rank::Six),
+                    card(Suit::Hearts, Rank::Ace),
+                ],
+                &[
+                    card(Suit::Spades, Rank::King),
+                    card(Suit::Spades, Rank::Three),
+                    card(Suit::Spades, Rank::Four),
+                    card(Suit::Spades, Rank::Six),
+                    card(Suit::Spades, Rank::Two),
+                    card(Suit::Spades, Rank::Nine),
+                ],
+                &[
+                    card(Suit::Clubs, Rank::Five),
+                    card(Suit::Clubs, Rank::Seven),
+                ],
+            ],
+        );
+
+        assert_eq!(game.play_round().unwrap(), None);
+
+        // Player 3 lost only their face-up card and kept the rest
+        assert_eq!(game.engine.hands[0].len(), 11);
+        assert_eq!(game.engine.hands[1].len(), 1);
+        assert_eq!(game.engine.hands[2].len(), 1);
+        assert_eq!(game.stats().biggest_war, 11);
+    }
+
+    #[test]
+    fn test_war_survivor_takes_the_pot_when_the_others_run_out() {
+        let card = Card::new;
+        let config = GameConfig {
+            players: 3,
+            ..GameConfig::default()
+        };
+        // Three Queens; players 1 and 2 run out while burning
+        let mut game = game_with_table(
+            config,
+            &[
+                &[
+                    card(Suit::Hearts, Rank::Queen),
+                    card(Suit::Hearts, Rank::Two),
+                ],
+                &[card(Suit::Spades, Rank::Queen)],
+                &[
+                    card(Suit::Clubs, Rank::Queen),
+                    card(Suit::Clubs, Rank::Two),
+                    card(Suit::Clubs, Rank::Three),
+                ],
+            ],
+        );
+
+        assert_eq!(game.play_round().unwrap(), Some(3));
+        assert_eq!(game.engine.hands[2].len(), 6);
+        assert!(game.engine.battle_buffer.is_empty());
+    }
+
+    #[test]
+    fn test_four_player_game_runs_to_completion() {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(7);
+        let config = GameConfig {
+            players: 4,
+            ..GameConfig::default()
+        };
+        let mut game = WarGame::from_deck(deck, config, io::empty());
+        assert!(game.engine.hands.iter().all(|hand| hand.len() == 13));
+
+        game.play().unwrap();
+
+        let outcome = game.outcome().unwrap();
+        assert!(
+            outcome
+                .winner
+                .is_none_or(|winner| (1..=4).contains(&winner))
+        );
+        let held: usize = game.engine.hands.iter().map(PlayerHand::len).sum();
+        assert_eq!(held, 52);
+        if outcome.reason == EndReason::OutOfCards {
+            assert_eq!(game.engine.hands[outcome.winner.unwrap() - 1].len(), 52);
+        }
+    }
+
+    #[test]
+    fn test_resolve_trick_matches_resolve_round_for_pairs() {
+        let config = GameConfig {
+            war_tolerance: 1,
+            ..GameConfig::default()
+        };
+        for card1 in Card::all() {
+            for card2 in [Rank::Two, Rank::Nine, Rank::Ace].map(|rank| Card::new(Suit::Clubs, rank))
+            {
+                let expected = match resolve_round(card1, card2, &config) {
+                    RoundResult::Win(winner) => vec![winner],
+                    RoundResult::War => vec![1, 2],
+                };
+                assert_eq!(
+                    resolve_trick(&[(1, card1), (2, card2)], &config)[..],
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_looping_seed_is_declared_a_draw() {
+        use crate::observer::NullObserver;
+
+        let play = |detect_cycles| {
+            let mut deck = Deck::new();
+            deck.shuffle_with_seed(23);
+            let config = GameConfig {
+                detect_cycles,
+                ..GameConfig::default()
+            };
+            let mut game = WarGame::from_deck(deck, config, io::empty());
+            game.set_observer(NullObserver);
+            game.play().unwrap();
+            game.outcome().unwrap()
+        };
+
+        // Seed 23 settles into a loop and would run to the round limit
+        assert_eq!(play(false).reason, EndReason::TruncatedByLimit);
+        assert_eq!(
+            play(true),
+            GameOutcome {
+                winner: None,
+                rounds: 260,
+                reason: EndReason::Cycle,
+                final_counts: [26, 26].into_iter().collect(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_seeded_game_stats() {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(42);
+        let mut game = WarGame::from_deck(deck, GameConfig::default(), io::empty());
+        game.set_observer(NullObserver);
+        game.play().unwrap();
+
+        let stats = game.stats();
+        assert_eq!(stats.rounds, 666);
+        assert_eq!(stats.wars, 25);
+        assert_eq!(stats.longest_war, 2);
+        assert_eq!(stats.biggest_war, 18);
+        // The winner ends holding the whole deck
+        assert_eq!(stats.max_lead, 52);
+        assert_eq!(stats.cards_exchanged, 772);
+    }
+
+    #[test]
+    fn test_jokers_beat_everything_but_each_other() {
+        let config = GameConfig::default();
+        let red = Card::new(Suit::Hearts, Rank::Joker);
+        let black = Card::new(Suit::Spades, Rank::Joker);
+        for card in Card::all() {
+            assert_eq!(resolve_round(red, card, &config), RoundResult::Win(1));
+            assert_eq!(resolve_round(card, black, &config), RoundResult::Win(2));
+        }
+        assert_eq!(resolve_round(red, black, &config), RoundResult::War);
+    }
+
+    #[test]
+    fn test_game_with_jokers_keeps_every_card() {
+        let mut deck = Deck::new_with_jokers(MAX_JOKERS);
+        deck.shuffle_with_seed(11);
+        let mut game = WarGame::from_deck(deck, GameConfig::default(), io::empty());
+        game.set_observer(NullObserver);
+        let outcome = game.play().unwrap();
+
+        assert_eq!(outcome.final_counts.iter().sum::<usize>(), 54);
+    }
+
+    #[test]
+    fn test_ace_low_lets_two_beat_ace() {
+        let ace = Card::new(Suit::Spades, Rank::Ace);
+        let two = Card::new(Suit::Hearts, Rank::Two);
+
+        let ace_high = GameConfig::default();
+        assert_eq!(resolve_round(ace, two, &ace_high), RoundResult::Win(1));
+
+        let ace_low = GameConfig {
+            values: ValueTable::ace_low(),
+            ..GameConfig::default()
+        };
+        assert_eq!(resolve_round(ace, two, &ace_low), RoundResult::Win(2));
+        assert_eq!(ace.value_with(&ace_low.values), 1);
+        // Plain `value` stays Ace-high
+        assert_eq!(ace.value(), 14);
+    }
+
+    #[test]
+    fn test_saved_game_resumes_to_the_same_outcome() {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(42);
+        let config = GameConfig {
+            target_score: Some(2000),
+            ..GameConfig::default()
+        };
+        let mut game = WarGame::from_deck(deck, config, io::empty());
+        game.set_observer(NullObserver);
+        for _ in 0..100 {
+            assert_eq!(game.play_round().unwrap(), None);
+        }
+
+        let path = std::env::temp_dir().join(format!("war-rust-save-{}.json", std::process::id()));
+        game.save_to(&path).unwrap();
+        let mut resumed: WarGame<_> =
+            WarGame::load_from(&path, GameConfig::default(), io::empty()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        resumed.set_observer(NullObserver);
+
+        assert_eq!(resumed.engine.round, 100);
+        assert_eq!(resumed.engine.config.target_score, Some(2000));
+        assert_eq!(resumed.stats().scores, game.stats().scores);
+        assert_eq!(resumed.play().unwrap(), game.play().unwrap());
+    }
+
+    #[test]
+    fn test_engine_steps_to_the_same_outcome_as_play() {
+        let deal = || {
+            let mut deck = Deck::new();
+            deck.shuffle_with_seed(42);
+            deck
+        };
+        let mut engine = GameEngine::from_deck(deal(), GameConfig::default());
+        let mut steps = 0;
+        let outcome = loop {
+            steps += 1;
+            match engine.step().unwrap() {
+                StepResult::Played => assert!(!engine.is_finished()),
+                StepResult::Finished(outcome) => break outcome,
+            }
+        };
+
+        let mut game = WarGame::from_deck(deal(), GameConfig::default(), io::empty());
+        game.set_observer(NullObserver);
+        assert_eq!(game.play().unwrap(), outcome);
+        assert_eq!(engine.stats(), game.stats());
+        assert_eq!(outcome.rounds, steps);
+        assert_eq!(engine.winner(), outcome.winner);
+
+        // A finished engine just reports the outcome again
+        assert_eq!(engine.step().unwrap(), StepResult::Finished(outcome));
+        assert_eq!(engine.round(), steps);
+    }
+}
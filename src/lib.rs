@@ -1,5 +1,60 @@
+pub mod animation;
+// Batch timing relies on `Instant`, which wasm32 doesn't provide
+#[cfg(not(target_arch = "wasm32"))]
+pub mod batch;
+pub mod card_set;
 pub mod cards;
+pub mod cli;
+pub mod daily;
+pub mod engine;
+pub mod events;
+pub mod game;
+pub mod observer;
+// Phase timing relies on `Instant`, which wasm32 doesn't provide
+#[cfg(not(target_arch = "wasm32"))]
+pub mod profile;
+pub mod replay;
 pub mod ring_buffer;
+pub mod save;
+pub mod simulation;
+#[cfg(feature = "spectate")]
+pub mod spectate;
+pub mod stats;
+pub mod tournament;
+#[cfg(feature = "tui")]
+pub mod tui;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
-pub use cards::{Card, Deck, PlayerHand, Rank, Suit};
+#[cfg(not(target_arch = "wasm32"))]
+pub use batch::{
+    BatchSummary, Progress, WinStats, estimate_wins, parse_seeds, read_seeds, report_seeds,
+    simulate_many, simulate_many_with_progress,
+};
+pub use card_set::CardSet;
+pub use cards::{
+    Card, Deck, MAX_DECK_SIZE, MAX_JOKERS, ParseCardError, PlayedCard, PlayerHand, PotOrder, Rank,
+    Suit, ValueTable, max_card, min_card,
+};
+pub use cli::{Args, Command};
+pub use daily::{Date, daily_seed, passphrase_seed};
+pub use engine::{GameEngine, StepResult};
+pub use events::{GameEvent, JsonObserver, OutputFormat};
+pub use game::{
+    DEFAULT_MAX_ROUNDS, EndReason, GameConfig, GameError, GameOutcome, GameResult, MAX_PLAYERS,
+    RoundResult, Seats, WarGame, WarVariant, default_observer, resolve_round, resolve_trick,
+};
+pub use observer::{ConsoleObserver, GameObserver, NullObserver};
+#[cfg(not(target_arch = "wasm32"))]
+pub use profile::{Profile, profile_game};
+pub use replay::{Replay, ReplayRecorder, RoundRecord};
 pub use ring_buffer::RingBuffer;
+pub use save::SavedGame;
+pub use simulation::{
+    GameSummary, MirrorCheck, RoundSummary, SeedComparison, Simulation, compare_seeds,
+    mirror_check, simulate, simulate_with,
+};
+#[cfg(feature = "spectate")]
+pub use spectate::{SPECTATE_ROUND_DELAY, Spectators, broadcast_game};
+pub use stats::{GameStats, STREAK_THRESHOLD};
+pub use tournament::{Bracket, Entrant, MatchResult, run_bracket};
@@ -1,364 +1,11 @@
-pub mod cards;
-pub mod ring_buffer;
-
-use cards::{Card, Deck, PlayerHand};
 use clap::Parser;
-use ring_buffer::RingBuffer;
-use std::fmt;
-use std::io::{self, Read, Write};
 use std::mem;
-
-#[derive(Debug)]
-pub enum GameError {
-    PlayerOutOfCards(usize),
-    InvalidPlayerNumber(usize),
-    BattleBufferFull,
-    IoError(io::Error),
-}
-
-impl fmt::Display for GameError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            GameError::PlayerOutOfCards(player) => write!(f, "Player {} is out of cards", player),
-            GameError::InvalidPlayerNumber(player) => {
-                write!(f, "Invalid player number: {}", player)
-            }
-            GameError::BattleBufferFull => write!(f, "Battle buffer is full - cannot continue war"),
-            GameError::IoError(e) => write!(f, "I/O error: {}", e),
-        }
-    }
-}
-
-impl std::error::Error for GameError {}
-
-impl From<io::Error> for GameError {
-    fn from(error: io::Error) -> Self {
-        GameError::IoError(error)
-    }
-}
-
-type GameResult<T> = Result<T, GameError>;
-
-#[derive(Parser)]
-#[command(name = "war-rust")]
-#[command(about = "A War card game implementation in Rust")]
-#[command(version = "0.1.0")]
-struct Args {
-    /// Enable test mode (game ends after 20 rounds)
-    #[arg(short, long)]
-    test: bool,
-
-    /// Enable interactive mode (press SPACE to continue each round)
-    #[arg(short, long)]
-    interactive: bool,
-
-    /// Set random seed for deterministic gameplay
-    #[arg(short, long)]
-    seed: Option<u64>,
-}
-
-const WAR_BANNER: &str = r#"
-  _____                  ____        _____
- |\    \   _____    ____|\   \   ___|\    \
- | |    | /    /|  /    /\    \ |    |\    \
- \/     / |    || |    |  |    ||    | |    |
- /     /_  \   \/ |    |__|    ||    |/____/
-|     // \  \   \ |    .--.    ||    |\    \
-|    |/   \ |    ||    |  |    ||    | |    |
-|\ ___/\   \|   /||____|  |____||____| |____|
-| |   | \______/ ||    |  |    ||    | |    |
- \|___|/\ |    | ||____|  |____||____| |____|
-    \(   \|____|/   \(      )/    \(     )/
-     '      )/       '      '      '     '
-            '
-
-"#;
-
-struct WarGame {
-    player1_cards: PlayerHand,
-    player2_cards: PlayerHand,
-    battle_buffer: RingBuffer<Card, 52>,
-    round: usize,
-    test_mode: bool,
-    interactive: bool,
-}
-
-impl WarGame {
-    fn new(test_mode: bool, interactive: bool) -> Self {
-        let mut deck = Deck::new();
-        deck.shuffle();
-        let (player1_cards, player2_cards) = deck.split();
-
-        WarGame {
-            player1_cards,
-            player2_cards,
-            battle_buffer: RingBuffer::new(Card::new(cards::Suit::Hearts, cards::Rank::Two)),
-            round: 0,
-            test_mode,
-            interactive,
-        }
-    }
-
-    fn new_with_seed(test_mode: bool, interactive: bool, seed: u64) -> Self {
-        let mut deck = Deck::new();
-        deck.shuffle_with_seed(seed);
-        let (player1_cards, player2_cards) = deck.split();
-
-        WarGame {
-            player1_cards,
-            player2_cards,
-            battle_buffer: RingBuffer::new(Card::new(cards::Suit::Hearts, cards::Rank::Two)),
-            round: 0,
-            test_mode,
-            interactive,
-        }
-    }
-
-    fn wait_for_space(&self) -> GameResult<()> {
-        if self.interactive {
-            print!("Press SPACE to continue...");
-            io::stdout().flush()?;
-
-            let mut buffer = [0; 1];
-            loop {
-                match io::stdin().read_exact(&mut buffer) {
-                    Ok(_) => {
-                        if buffer[0] == b' ' {
-                            break;
-                        }
-                    }
-                    Err(e) => return Err(GameError::IoError(e)),
-                }
-            }
-            println!(); // New line after space is pressed
-        }
-        Ok(())
-    }
-
-    fn log_card_draw(&self, player: usize, card: Card) {
-        println!(
-            "🃏 Player {} draws: {} {:?} (value: {})",
-            player,
-            card.suit_symbol(),
-            card.rank(),
-            card.value()
-        );
-    }
-
-    fn draw_card(&mut self, player: usize) -> GameResult<Option<Card>> {
-        match player {
-            1 => Ok(self.player1_cards.draw_card()),
-            2 => Ok(self.player2_cards.draw_card()),
-            _ => Err(GameError::InvalidPlayerNumber(player)),
-        }
-    }
-
-    fn add_cards_to_winner(&mut self, winner: usize) -> GameResult<()> {
-        match winner {
-            1 => {
-                self.player1_cards.take_battle_cards(&self.battle_buffer);
-            }
-            2 => {
-                self.player2_cards.take_battle_cards(&self.battle_buffer);
-            }
-            _ => return Err(GameError::InvalidPlayerNumber(winner)),
-        }
-        self.battle_buffer.clear();
-        Ok(())
-    }
-
-    fn play_round(&mut self) -> GameResult<Option<usize>> {
-        self.round += 1;
-
-        if self.player1_cards.is_empty() {
-            return Ok(Some(2));
-        }
-        if self.player2_cards.is_empty() {
-            return Ok(Some(1));
-        }
-
-        println!("\n--- Round {} ---", self.round);
-        println!(
-            "Player 1 has {} cards, Player 2 has {} cards",
-            self.player1_cards.len(),
-            self.player2_cards.len()
-        );
-
-        // Clear and reuse the battle buffer
-        self.battle_buffer.clear();
-
-        // Draw initial cards
-        let card1 = self.draw_card(1)?.ok_or(GameError::PlayerOutOfCards(1))?;
-        let card2 = self.draw_card(2)?.ok_or(GameError::PlayerOutOfCards(2))?;
-        self.log_card_draw(1, card1);
-        self.log_card_draw(2, card2);
-        self.battle_buffer.push_back(card1);
-        self.battle_buffer.push_back(card2);
-
-        println!(
-            "Player 1 plays: {} {:?} (value: {})",
-            card1.suit_symbol(),
-            card1.rank(),
-            card1.value()
-        );
-        println!(
-            "Player 2 plays: {} {:?} (value: {})",
-            card2.suit_symbol(),
-            card2.rank(),
-            card2.value()
-        );
-
-        if card1.value() > card2.value() {
-            println!("Player 1 wins the round!");
-            self.add_cards_to_winner(1)?;
-        } else if card2.value() > card1.value() {
-            println!("Player 2 wins the round!");
-            self.add_cards_to_winner(2)?;
-        } else {
-            println!("WAR! Cards are equal ({})", card1.value());
-            println!("{}", WAR_BANNER);
-            self.wait_for_space()?;
-
-            // War scenario - burn 3 cards each and draw another
-            for i in 1..=3 {
-                if let Some(burn1) = self.draw_card(1)? {
-                    self.log_card_draw(1, burn1);
-                    self.battle_buffer.push_back(burn1);
-                    println!(
-                        "Player 1 burns card {}: {} {:?}",
-                        i,
-                        burn1.suit_symbol(),
-                        burn1.rank()
-                    );
-                } else {
-                    println!("Player 1 runs out of cards during war!");
-                    return Ok(Some(2));
-                }
-
-                if let Some(burn2) = self.draw_card(2)? {
-                    self.log_card_draw(2, burn2);
-                    self.battle_buffer.push_back(burn2);
-                    println!(
-                        "Player 2 burns card {}: {} {:?}",
-                        i,
-                        burn2.suit_symbol(),
-                        burn2.rank()
-                    );
-                } else {
-                    println!("Player 2 runs out of cards during war!");
-                    return Ok(Some(1));
-                }
-            }
-
-            // Draw the deciding cards
-            if let Some(war_card1) = self.draw_card(1)? {
-                if let Some(war_card2) = self.draw_card(2)? {
-                    self.log_card_draw(1, war_card1);
-                    self.log_card_draw(2, war_card2);
-                    self.battle_buffer.push_back(war_card1);
-                    self.battle_buffer.push_back(war_card2);
-
-                    println!(
-                        "War cards - Player 1: {} {:?} ({}), Player 2: {} {:?} ({})",
-                        war_card1.suit_symbol(),
-                        war_card1.rank(),
-                        war_card1.value(),
-                        war_card2.suit_symbol(),
-                        war_card2.rank(),
-                        war_card2.value()
-                    );
-
-                    if war_card1.value() > war_card2.value() {
-                        println!("Player 1 wins the war!");
-                        self.add_cards_to_winner(1)?;
-                    } else if war_card2.value() > war_card1.value() {
-                        println!("Player 2 wins the war!");
-                        self.add_cards_to_winner(2)?;
-                    } else {
-                        println!(
-                            "Another war would be needed, but for simplicity, Player 1 wins this tie!"
-                        );
-                        self.add_cards_to_winner(1)?;
-                    }
-                } else {
-                    println!("Player 2 runs out of cards during war!");
-                    return Ok(Some(1));
-                }
-            } else {
-                println!("Player 1 runs out of cards during war!");
-                return Ok(Some(2));
-            }
-        }
-
-        self.wait_for_space()?;
-        Ok(None) // Game continues
-    }
-
-    fn play(&mut self) -> GameResult<()> {
-        println!("🎮 Starting War Card Game!");
-        println!("Each player starts with 26 cards.");
-
-        if self.test_mode {
-            println!("🧪 TEST MODE: Game will end after 20 rounds.");
-        }
-        if self.interactive {
-            println!("🎮 INTERACTIVE MODE: Press SPACE after each round to continue.");
-        }
-        println!();
-
-        let max_rounds: usize = if self.test_mode { 20 } else { 10000 };
-
-        loop {
-            match self.play_round()? {
-                Some(winner) => {
-                    println!("\n🎉 GAME OVER! 🎉");
-                    println!(
-                        "Player {} wins the game after {} rounds!",
-                        winner, self.round
-                    );
-                    println!(
-                        "Final card counts - Player 1: {}, Player 2: {}",
-                        self.player1_cards.len(),
-                        self.player2_cards.len()
-                    );
-                    break;
-                }
-                None => {} // Game continues
-            }
-
-            // Check if we've reached the limit
-            if self.round >= max_rounds {
-                if self.test_mode {
-                    println!("\n🧪 TEST MODE: Completed {} rounds!", self.round);
-                    println!(
-                        "Current card counts - Player 1: {}, Player 2: {}",
-                        self.player1_cards.len(),
-                        self.player2_cards.len()
-                    );
-
-                    if self.player1_cards.len() > self.player2_cards.len() {
-                        println!("Player 1 is currently winning!");
-                    } else if self.player2_cards.len() > self.player1_cards.len() {
-                        println!("Player 2 is currently winning!");
-                    } else {
-                        println!("It's currently tied!");
-                    }
-                } else {
-                    println!("\nGame limit reached! Declaring winner based on card count.");
-                    if self.player1_cards.len() > self.player2_cards.len() {
-                        println!("Player 1 wins with {} cards!", self.player1_cards.len());
-                    } else if self.player2_cards.len() > self.player1_cards.len() {
-                        println!("Player 2 wins with {} cards!", self.player2_cards.len());
-                    } else {
-                        println!("It's a tie!");
-                    }
-                }
-                break;
-            }
-        }
-        Ok(())
-    }
-}
+use std::path::Path;
+use war_rust::{
+    Args, Card, Command, Date, Deck, Entrant, GameConfig, GameEngine, OutputFormat, PlayerHand,
+    Replay, ReplayRecorder, RingBuffer, WarGame, compare_seeds, daily_seed, default_observer,
+    estimate_wins, passphrase_seed, profile_game, read_seeds, report_seeds, run_bracket,
+};
 
 fn show_memory_layout() {
     println!("\n📊 Memory Layout Information:");
@@ -386,17 +33,20 @@ fn show_memory_layout() {
         mem::needs_drop::<RingBuffer<Card, 52>>()
     );
 
+    println!("GameEngine size: {} bytes", mem::size_of::<GameEngine>());
     println!("WarGame size: {} bytes", mem::size_of::<WarGame>());
     println!("WarGame alignment: {} bytes", mem::align_of::<WarGame>());
     println!("WarGame needs drop: {}", mem::needs_drop::<WarGame>());
 
-    println!("\n🚀 ZERO HEAP ALLOCATIONS!");
-    println!("✅ Entire game state lives on the stack");
-    println!("✅ No Vec, no Box, no heap pointers");
+    println!("\n🚀 ZERO HEAP ALLOCATIONS PER ROUND!");
+    println!("✅ Hands and pot live inline in the GameEngine: no Vec, no Box");
+    println!("✅ Playing a dealt game through GameEngine::step never allocates");
     println!(
-        "✅ Maximum predictable memory usage: {} bytes",
-        mem::size_of::<WarGame>()
+        "✅ Predictable game state size: {} bytes",
+        mem::size_of::<GameEngine>()
     );
+    println!("ℹ️  Except: --detect-cycles stores a fingerprint of every position");
+    println!("ℹ️  Except: WarGame boxes its GameObserver, and narration formats text on the heap");
 
     // For comparison, show what Vec<Card> would be like
     println!("\n📈 Comparison to Vec<Card>:");
@@ -411,20 +61,261 @@ fn show_memory_layout() {
     println!();
 }
 
+/// Print a seeded deal in card codes: the deck top first, then each hand in draw order
+fn export_deck(config: &GameConfig, seed: u64) {
+    let mut deck = Deck::new();
+    deck.shuffle_with_seed(seed);
+    println!("🎲 Using seed: {}", seed);
+    println!("Deck:     {}", deck.to_codes());
+
+    let hands = deck.deal_with_offset(config.players, config.deal_offset);
+    for (player, mut hand) in (1..).zip(hands) {
+        let codes: Vec<String> = std::iter::from_fn(|| hand.draw_card())
+            .map(|card| card.code())
+            .collect();
+        println!("Player {}: {}", player, codes.join(" "));
+    }
+}
+
+/// Narrate a recorded game, then optionally check it against the engine
+fn replay(path: &Path, verify: bool) {
+    let replay = match Replay::load_from(path) {
+        Ok(replay) => replay,
+        Err(e) => {
+            eprintln!("❌ {}: {}", path.display(), e);
+            std::process::exit(2);
+        }
+    };
+    println!("🎬 Replaying seed {}", replay.seed);
+    for event in replay.events() {
+        println!("{}", event);
+    }
+
+    if verify {
+        match replay.diverges_at() {
+            Ok(None) => println!(
+                "✅ The engine still plays all {} rounds as recorded",
+                replay.rounds.len()
+            ),
+            Ok(Some(round)) => {
+                eprintln!(
+                    "❌ The engine plays round {} differently than recorded",
+                    round
+                );
+                std::process::exit(1);
+            }
+            Err(e) => {
+                eprintln!("❌ {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "spectate")]
+fn spectate(addr: &str, seed: u64, config: GameConfig, max_rounds: usize) {
+    use war_rust::animation::SystemClock;
+    use war_rust::{SPECTATE_ROUND_DELAY, Simulation, Spectators, broadcast_game};
+
+    let mut spectators = match Spectators::bind(addr) {
+        Ok(spectators) => spectators,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    };
+    if let Ok(local) = spectators.local_addr() {
+        println!("📡 Broadcasting seed {} to spectators on {}", seed, local);
+    }
+
+    let mut deck = Deck::new();
+    deck.shuffle_with_seed(seed);
+    let summary = broadcast_game(
+        &mut Simulation::from_deck(deck, config),
+        &mut spectators,
+        &mut SystemClock,
+        SPECTATE_ROUND_DELAY,
+        max_rounds,
+    );
+    println!("{}", summary);
+}
+
 fn main() {
     let args = Args::parse();
+    let config = match GameConfig::from_args(&args) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(2);
+        }
+    };
 
-    show_memory_layout();
+    if let Some(Command::Replay { file, verify }) = &args.command {
+        replay(file, *verify);
+        return;
+    }
 
-    let mut game = if let Some(seed) = args.seed {
+    // Headless modes stop where a game at the terminal would: --max-rounds,
+    // or else 20 rounds under --test
+    let max_rounds = config.round_limit();
+
+    if let Some(Command::Analyze { samples }) = args.command {
+        // Like a tournament, the seeds count up from --seed so a run can be repeated
+        let base_seed = args.seed.unwrap_or(0);
+        let seeds = (0..samples).map(|i| base_seed.wrapping_add(i));
+        println!("{}", estimate_wins(seeds, config, max_rounds));
+        return;
+    }
+
+    if let Some(players) = args.tournament {
+        // Entrant seeds count up from --seed so a bracket can be replayed
+        let base_seed = args.seed.unwrap_or(0);
+        let entrants = (0..players)
+            .map(|i| Entrant {
+                name: format!("Player {}", i + 1),
+                seed: base_seed.wrapping_add(i as u64),
+            })
+            .collect();
+
+        match run_bracket(entrants, config, max_rounds) {
+            Ok(bracket) => println!("{}", bracket),
+            Err(e) => {
+                eprintln!("❌ {}", e);
+                std::process::exit(2);
+            }
+        }
+        return;
+    }
+
+    if args.profile {
+        let seed = args.seed.unwrap_or_else(rand::random);
+        let (profile, summary) = profile_game(seed, config, max_rounds);
         println!("🎲 Using seed: {}", seed);
-        WarGame::new_with_seed(args.test, args.interactive, seed)
+        println!("{}", summary);
+        print!("{}", profile);
+        return;
+    }
+
+    if args.export_deck {
+        export_deck(&config, args.seed.unwrap_or_else(rand::random));
+        return;
+    }
+
+    if let Some(path) = &args.seeds_from_file {
+        let seeds = match read_seeds(path) {
+            Ok(seeds) => seeds,
+            Err(e) => {
+                eprintln!("❌ {}: {}", path.display(), e);
+                std::process::exit(2);
+            }
+        };
+        match report_seeds(&seeds, config, max_rounds, &mut std::io::stdout().lock()) {
+            Ok(batch) => println!("{}", batch),
+            Err(e) => {
+                eprintln!("❌ {}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    if let Some(seeds) = &args.compare_seeds {
+        println!("{}", compare_seeds(seeds[0], seeds[1], config, max_rounds));
+        return;
+    }
+
+    #[cfg(feature = "spectate")]
+    if let Some(addr) = &args.spectate {
+        spectate(
+            addr,
+            args.seed.unwrap_or_else(rand::random),
+            config,
+            max_rounds,
+        );
+        return;
+    }
+
+    #[cfg(feature = "tui")]
+    if args.tui {
+        if let Err(e) = war_rust::tui::run(args.seed.unwrap_or_else(rand::random), config) {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    // JSON output carries nothing but events and quiet output nothing but
+    // the result, so the banners stay out of both
+    let text = config.format == OutputFormat::Text && !config.quiet;
+    if text {
+        show_memory_layout();
+    }
+
+    let seed = if args.daily {
+        let today = Date::today_utc();
+        let seed = daily_seed(today);
+        if text {
+            println!("📅 Daily challenge for {} (seed {})", today, seed);
+        }
+        Some(seed)
+    } else if let Some(phrase) = &args.passphrase {
+        let seed = passphrase_seed(phrase);
+        if text {
+            println!("🔑 Passphrase \"{}\" gives seed {}", phrase, seed);
+        }
+        Some(seed)
+    } else if args.record.is_some() {
+        // A replay is only as good as its seed, so recorded games always have one
+        Some(args.seed.unwrap_or_else(rand::random))
+    } else {
+        args.seed
+    };
+
+    let mut game = if let Some(path) = &args.load {
+        match WarGame::load_from(path, config, std::io::stdin()) {
+            Ok(game) => game,
+            Err(e) => {
+                eprintln!("❌ {}: {}", path.display(), e);
+                std::process::exit(2);
+            }
+        }
+    } else if let Some(seed) = seed {
+        if text {
+            println!("🎲 Using seed: {}", seed);
+        }
+        WarGame::new_with_seed(config, seed)
     } else {
-        WarGame::new(args.test, args.interactive)
+        WarGame::new(config)
     };
 
-    if let Err(e) = game.play() {
-        eprintln!("❌ Game error: {}", e);
-        std::process::exit(1);
+    if let Some(path) = &args.save {
+        game.autosave_to(path.clone());
+    }
+    let recorder = seed
+        .filter(|_| args.record.is_some())
+        .map(|seed| ReplayRecorder::new(seed, config));
+    if let Some(recorder) = &recorder {
+        game.set_observer((default_observer(&config), recorder.clone()));
+    }
+
+    match game.play() {
+        // Quiet games narrate nothing, so the result is reported here
+        Ok(outcome) if config.quiet => {
+            println!("{} ({} wars)", outcome, game.stats().wars)
+        }
+        Ok(_) => {}
+        Err(e) => {
+            eprintln!("❌ Game error: {}", e);
+            std::process::exit(1);
+        }
+    }
+    if let (Some(path), Some(recorder)) = (&args.record, &recorder) {
+        if let Err(e) = recorder.replay().save_to(path) {
+            eprintln!("❌ {}: {}", path.display(), e);
+            std::process::exit(1);
+        }
+    }
+    if args.stats {
+        println!("\n📊 Game statistics:\n{}", game.stats());
     }
 }
@@ -0,0 +1,439 @@
+use crate::animation::{REVEAL_DELAY, SystemClock, slow_reveal};
+use crate::cards::{Card, PlayedCard};
+use crate::game::{EndReason, GameConfig, GameOutcome};
+use crate::stats::{GameStats, count_leader};
+use std::io;
+
+/// Hooks for following a `WarGame` (or a `GameEngine` stepped with
+/// `step_observed`) as it is played
+/// Every method has an empty default, so an observer only implements the
+/// events it cares about. The game itself prints nothing but its keypress
+/// prompts; all narration comes from its observer
+/// Players are numbered from 1, and `card_counts` lists each player's cards,
+/// player 1 first
+pub trait GameObserver {
+    /// `play` is about to start the first round
+    fn on_game_start(&mut self, _card_counts: &[usize]) {}
+
+    /// `round` is about to be played
+    fn on_round_start(&mut self, _round: usize, _card_counts: &[usize]) {}
+
+    /// `player` turned `card` face up, to start a round or decide a war
+    fn on_card_drawn(&mut self, _player: usize, _card: PlayedCard) {}
+
+    /// `player` laid their `number`th war card (from 1) face down, unless
+    /// `show_burns` turned it face up
+    fn on_card_burned(&mut self, _player: usize, _number: usize, _card: PlayedCard) {}
+
+    /// Every player's face-up card for this trick is on the table, in play order
+    fn on_cards_revealed(&mut self, _cards: &[(usize, Card)]) {}
+
+    /// `players` tied and are going to war over `pot_size` cards; fires
+    /// again for each further level of the same war
+    fn on_war(&mut self, _players: &[usize], _pot_size: usize) {}
+
+    /// `player` has no cards left to carry on the war and drops out of it
+    fn on_out_of_cards(&mut self, _player: usize) {}
+
+    /// `winner` takes the pot of `pot_size` cards, ending `round`
+    fn on_round_won(&mut self, _round: usize, _winner: usize, _pot_size: usize) {}
+
+    /// `winner` has just taken `cards`, in the order they were played
+    fn on_pot_won(&mut self, _winner: usize, _cards: &[Card]) {}
+
+    /// `player` has won `length` rounds in a row, `STREAK_THRESHOLD` of them
+    fn on_streak(&mut self, _player: usize, _length: usize) {}
+
+    /// The game is over
+    fn on_game_over(&mut self, _outcome: &GameOutcome, _stats: &GameStats) {}
+}
+
+/// Ignores every event, for running games silently (e.g. in benchmarks)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NullObserver;
+
+impl GameObserver for NullObserver {}
+
+/// Forwards to the boxed observer, so a boxed observer can be combined
+impl<O: GameObserver + ?Sized> GameObserver for Box<O> {
+    fn on_game_start(&mut self, card_counts: &[usize]) {
+        (**self).on_game_start(card_counts);
+    }
+
+    fn on_round_start(&mut self, round: usize, card_counts: &[usize]) {
+        (**self).on_round_start(round, card_counts);
+    }
+
+    fn on_card_drawn(&mut self, player: usize, card: PlayedCard) {
+        (**self).on_card_drawn(player, card);
+    }
+
+    fn on_card_burned(&mut self, player: usize, number: usize, card: PlayedCard) {
+        (**self).on_card_burned(player, number, card);
+    }
+
+    fn on_cards_revealed(&mut self, cards: &[(usize, Card)]) {
+        (**self).on_cards_revealed(cards);
+    }
+
+    fn on_war(&mut self, players: &[usize], pot_size: usize) {
+        (**self).on_war(players, pot_size);
+    }
+
+    fn on_out_of_cards(&mut self, player: usize) {
+        (**self).on_out_of_cards(player);
+    }
+
+    fn on_round_won(&mut self, round: usize, winner: usize, pot_size: usize) {
+        (**self).on_round_won(round, winner, pot_size);
+    }
+
+    fn on_pot_won(&mut self, winner: usize, cards: &[Card]) {
+        (**self).on_pot_won(winner, cards);
+    }
+
+    fn on_streak(&mut self, player: usize, length: usize) {
+        (**self).on_streak(player, length);
+    }
+
+    fn on_game_over(&mut self, outcome: &GameOutcome, stats: &GameStats) {
+        (**self).on_game_over(outcome, stats);
+    }
+}
+
+/// Reports every event to both observers, the first one first, e.g. to
+/// narrate a game and record it at the same time
+impl<A: GameObserver, B: GameObserver> GameObserver for (A, B) {
+    fn on_game_start(&mut self, card_counts: &[usize]) {
+        self.0.on_game_start(card_counts);
+        self.1.on_game_start(card_counts);
+    }
+
+    fn on_round_start(&mut self, round: usize, card_counts: &[usize]) {
+        self.0.on_round_start(round, card_counts);
+        self.1.on_round_start(round, card_counts);
+    }
+
+    fn on_card_drawn(&mut self, player: usize, card: PlayedCard) {
+        self.0.on_card_drawn(player, card);
+        self.1.on_card_drawn(player, card);
+    }
+
+    fn on_card_burned(&mut self, player: usize, number: usize, card: PlayedCard) {
+        self.0.on_card_burned(player, number, card);
+        self.1.on_card_burned(player, number, card);
+    }
+
+    fn on_cards_revealed(&mut self, cards: &[(usize, Card)]) {
+        self.0.on_cards_revealed(cards);
+        self.1.on_cards_revealed(cards);
+    }
+
+    fn on_war(&mut self, players: &[usize], pot_size: usize) {
+        self.0.on_war(players, pot_size);
+        self.1.on_war(players, pot_size);
+    }
+
+    fn on_out_of_cards(&mut self, player: usize) {
+        self.0.on_out_of_cards(player);
+        self.1.on_out_of_cards(player);
+    }
+
+    fn on_round_won(&mut self, round: usize, winner: usize, pot_size: usize) {
+        self.0.on_round_won(round, winner, pot_size);
+        self.1.on_round_won(round, winner, pot_size);
+    }
+
+    fn on_pot_won(&mut self, winner: usize, cards: &[Card]) {
+        self.0.on_pot_won(winner, cards);
+        self.1.on_pot_won(winner, cards);
+    }
+
+    fn on_streak(&mut self, player: usize, length: usize) {
+        self.0.on_streak(player, length);
+        self.1.on_streak(player, length);
+    }
+
+    fn on_game_over(&mut self, outcome: &GameOutcome, stats: &GameStats) {
+        self.0.on_game_over(outcome, stats);
+        self.1.on_game_over(outcome, stats);
+    }
+}
+
+const WAR_BANNER: &str = r#"
+  _____                  ____        _____
+ |\    \   _____    ____|\   \   ___|\    \
+ | |    | /    /|  /    /\    \ |    |\    \
+ \/     / |    || |    |  |    ||    | |    |
+ /     /_  \   \/ |    |__|    ||    |/____/
+|     // \  \   \ |    .--.    ||    |\    \
+|    |/   \ |    ||    |  |    ||    | |    |
+|\ ___/\   \|   /||____|  |____||____| |____|
+| |   | \______/ ||    |  |    ||    | |    |
+ \|___|/\ |    | ||____|  |____||____| |____|
+    \(   \|____|/   \(      )/    \(     )/
+     '      )/       '      '      '     '
+            '
+
+"#;
+
+/// Narrates a game to stdout in prose; `WarGame`'s default for text output
+#[derive(Debug, Clone)]
+pub struct ConsoleObserver {
+    config: GameConfig,
+    /// The face-up cards of the latest trick, to describe a war they start
+    trick: Vec<(usize, Card)>,
+    /// Players still fighting the current war, if one is under way
+    at_war: Vec<usize>,
+}
+
+impl ConsoleObserver {
+    /// Narrate a game played under `config`
+    pub fn new(config: GameConfig) -> Self {
+        ConsoleObserver {
+            config,
+            trick: Vec::new(),
+            at_war: Vec::new(),
+        }
+    }
+
+    fn value(&self, card: Card) -> u8 {
+        card.value_with(&self.config.values)
+    }
+
+    /// "Player 1: 26, Player 2: 26"
+    fn list(values: &[u64]) -> String {
+        let entries: Vec<String> = values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| format!("Player {}: {}", i + 1, value))
+            .collect();
+        entries.join(", ")
+    }
+
+    fn card_count_list(card_counts: &[usize]) -> String {
+        let counts: Vec<u64> = card_counts.iter().map(|&count| count as u64).collect();
+        Self::list(&counts)
+    }
+}
+
+/// "Players 1 and 3" or "Players 1, 2 and 4"
+pub(crate) fn player_list(players: &[usize]) -> String {
+    let names: Vec<String> = players.iter().map(usize::to_string).collect();
+    match names.split_last() {
+        Some((last, rest)) if !rest.is_empty() => {
+            format!("Players {} and {}", rest.join(", "), last)
+        }
+        _ => format!("Player {}", names.join("")),
+    }
+}
+
+impl GameObserver for ConsoleObserver {
+    fn on_game_start(&mut self, card_counts: &[usize]) {
+        println!("🎮 Starting War Card Game!");
+        let fewest = card_counts.iter().min().copied().unwrap_or(0);
+        let most = card_counts.iter().max().copied().unwrap_or(0);
+        if card_counts.len() > 2 {
+            println!("🪑 {} players at the table.", card_counts.len());
+        }
+        if fewest == most {
+            println!("Each player starts with {} cards.", most);
+        } else {
+            println!("Each player starts with {} or {} cards.", fewest, most);
+        }
+
+        if self.config.test_mode {
+            println!("🧪 TEST MODE: Game will end after 20 rounds.");
+        }
+        if self.config.interactive {
+            println!("🎮 INTERACTIVE MODE: Press SPACE after each round to continue.");
+        }
+        if self.config.hot_seat {
+            println!("🪑 HOT SEAT MODE: Each player presses SPACE to reveal their card.");
+        }
+        if let Some(target) = self.config.target_score {
+            println!("🪙 SCORING MODE: First player to {} chips wins.", target);
+        }
+        println!();
+    }
+
+    fn on_round_start(&mut self, round: usize, card_counts: &[usize]) {
+        self.at_war.clear();
+        println!("\n--- Round {} ---", round);
+        let holdings: Vec<String> = card_counts
+            .iter()
+            .enumerate()
+            .map(|(i, count)| format!("Player {} has {} cards", i + 1, count))
+            .collect();
+        println!("{}", holdings.join(", "));
+    }
+
+    fn on_card_drawn(&mut self, player: usize, card: PlayedCard) {
+        println!(
+            "🃏 Player {} draws: {} (value: {})",
+            player,
+            card,
+            self.value(card.card)
+        );
+    }
+
+    fn on_card_burned(&mut self, player: usize, number: usize, card: PlayedCard) {
+        if card.face_up {
+            self.on_card_drawn(player, card);
+        } else {
+            println!("🃏 Player {} draws: {}", player, card);
+        }
+        println!("Player {} burns card {}: {}", player, number, card);
+    }
+
+    fn on_cards_revealed(&mut self, cards: &[(usize, Card)]) {
+        if self.at_war.is_empty() {
+            for &(player, card) in cards {
+                println!(
+                    "Player {} plays: {} (value: {})",
+                    player,
+                    card,
+                    self.value(card)
+                );
+            }
+        } else {
+            let shown: Vec<String> = cards
+                .iter()
+                .map(|&(player, card)| {
+                    format!("Player {}: {} ({})", player, card, self.value(card))
+                })
+                .collect();
+            let reveal = format!("War cards - {}", shown.join(", "));
+            if self.config.animate {
+                // Narration is best effort, like `println!` but without the panic
+                let _ = slow_reveal(&mut io::stdout(), &mut SystemClock, &reveal, REVEAL_DELAY);
+            } else {
+                println!("{}", reveal);
+            }
+        }
+        self.trick = cards.to_vec();
+    }
+
+    fn on_war(&mut self, players: &[usize], pot_size: usize) {
+        if self.at_war.is_empty() {
+            let values: Vec<u8> = self
+                .trick
+                .iter()
+                .filter(|(player, _)| players.contains(player))
+                .map(|&(_, card)| self.value(card))
+                .collect();
+            if values.iter().all(|&value| value == values[0]) {
+                println!("WAR! Cards are equal ({})", values[0]);
+            } else {
+                let values: Vec<String> = values.iter().map(u8::to_string).collect();
+                println!(
+                    "WAR! Cards are within {} ({})",
+                    self.config.war_tolerance,
+                    values.join(" vs ")
+                );
+            }
+            if players.len() < self.trick.len() {
+                println!("{} go to war", player_list(players));
+            }
+            println!("{}", WAR_BANNER);
+        } else {
+            println!("Another WAR! {} cards are now at stake", pot_size);
+            if players.len() < self.trick.len() {
+                println!("{} go to war", player_list(players));
+            }
+        }
+        self.at_war = players.to_vec();
+    }
+
+    fn on_out_of_cards(&mut self, player: usize) {
+        println!("Player {} runs out of cards during war!", player);
+        self.at_war.retain(|&other| other != player);
+    }
+
+    fn on_round_won(&mut self, _round: usize, winner: usize, pot_size: usize) {
+        match self.at_war.len() {
+            0 => println!("Player {} wins the round!", winner),
+            // Everyone else ran out, which has already been announced
+            1 => {}
+            _ => println!("Player {} wins the war and {} cards!", winner, pot_size),
+        }
+    }
+
+    fn on_streak(&mut self, player: usize, length: usize) {
+        println!(
+            "🔥 Player {} is on a {}-round winning streak!",
+            player, length
+        );
+    }
+
+    fn on_game_over(&mut self, outcome: &GameOutcome, stats: &GameStats) {
+        let card_counts = &outcome.final_counts[..];
+        match (outcome.reason, outcome.winner) {
+            (EndReason::OutOfCards, Some(winner)) => {
+                println!("\n🎉 GAME OVER! 🎉");
+                println!(
+                    "Player {} wins the game after {} rounds!",
+                    winner, outcome.rounds
+                );
+                println!("Final card counts - {}", Self::card_count_list(card_counts));
+            }
+            (EndReason::TargetScore, Some(winner)) => {
+                println!("\n🎉 GAME OVER! 🎉");
+                println!(
+                    "Player {} reaches {} chips and wins after {} rounds!",
+                    winner,
+                    stats.score(winner),
+                    outcome.rounds
+                );
+                println!(
+                    "Final scores - {}",
+                    Self::list(&stats.scores[..card_counts.len()])
+                );
+            }
+            (EndReason::Cycle, _) => {
+                println!("\n🔁 GAME OVER! 🔁");
+                println!(
+                    "Round {} repeated an earlier position, so the game would never end. It's a draw!",
+                    outcome.rounds
+                );
+                println!("Final card counts - {}", Self::card_count_list(card_counts));
+            }
+            _ if self.config.test_mode => {
+                println!("\n🧪 TEST MODE: Completed {} rounds!", outcome.rounds);
+                println!(
+                    "Current card counts - {}",
+                    Self::card_count_list(card_counts)
+                );
+                match count_leader(card_counts) {
+                    Some(leader) => println!("Player {} is currently winning!", leader),
+                    None => println!("It's currently tied!"),
+                }
+            }
+            _ => {
+                println!(
+                    "\n✂️  Game cut off at the {}-round limit! This result is provisional, decided by card count.",
+                    outcome.rounds
+                );
+                match outcome.winner {
+                    Some(leader) => println!(
+                        "Player {} wins with {} cards!",
+                        leader,
+                        card_counts[leader - 1]
+                    ),
+                    None => println!("It's a tie!"),
+                }
+            }
+        }
+
+        if let Some(round) = outcome
+            .winner
+            .and_then(|player| stats.decided_at_round(player))
+        {
+            println!("📈 The winner took the lead for good in round {}.", round);
+        }
+        if stats.biggest_war > 0 {
+            println!("💥 Biggest war: {} cards", stats.biggest_war);
+        }
+        println!("🏃 Longest streak: {} rounds", stats.longest_streak);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::cards::Deck;
+use crate::game::GameConfig;
+use crate::simulation::{GameSummary, Simulation};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Wall-clock time spent in each phase of one headless game
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Profile {
+    pub deck_creation: Duration,
+    pub shuffle: Duration,
+    pub split: Duration,
+    pub play: Duration,
+}
+
+impl Profile {
+    pub fn total(&self) -> Duration {
+        self.deck_creation + self.shuffle + self.split + self.play
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "⏱️  Timing breakdown:")?;
+        for (label, duration) in [
+            ("Deck creation", self.deck_creation),
+            ("Shuffle", self.shuffle),
+            ("Split", self.split),
+            ("Play", self.play),
+            ("Total", self.total()),
+        ] {
+            writeln!(
+                f,
+                "  {:<14} {:>10.1} µs",
+                label,
+                duration.as_secs_f64() * 1e6
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Run the seeded game `simulate_with` would, timing each phase separately
+pub fn profile_game(seed: u64, config: GameConfig, max_rounds: usize) -> (Profile, GameSummary) {
+    let start = Instant::now();
+    let mut deck = Deck::new();
+    let deck_created = Instant::now();
+    deck.shuffle_with_seed(seed);
+    let shuffled = Instant::now();
+    let (player1, player2) = deck.split_with_offset(config.deal_offset);
+    let split = Instant::now();
+    let summary = Simulation::from_hands(player1, player2, config).run(max_rounds);
+    let played = Instant::now();
+
+    let profile = Profile {
+        deck_creation: deck_created - start,
+        shuffle: shuffled - deck_created,
+        split: split - shuffled,
+        play: played - split,
+    };
+    (profile, summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::DEFAULT_MAX_ROUNDS;
+    use crate::simulation::simulate_with;
+
+    #[test]
+    fn test_profile_plays_the_same_game_and_labels_every_phase() {
+        let config = GameConfig {
+            deal_offset: 1,
+            target_score: Some(300),
+            ..GameConfig::default()
+        };
+        let (profile, summary) = profile_game(42, config, DEFAULT_MAX_ROUNDS);
+        assert_eq!(summary, simulate_with(42, config, DEFAULT_MAX_ROUNDS));
+
+        let output = profile.to_string();
+        for label in ["Deck creation", "Shuffle", "Split", "Play", "Total"] {
+            assert!(output.contains(label), "missing {} in {}", label, output);
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use crate::cards::{Deck, PlayedCard};
+use crate::events::GameEvent;
+use crate::game::{GameConfig, GameOutcome, GameResult, MAX_PLAYERS, WarGame};
+use crate::observer::GameObserver;
+use crate::save::{Json, invalid, json_object, rules_from_json, rules_json, small};
+use crate::stats::GameStats;
+use std::cell::RefCell;
+use std::io;
+use std::path::Path;
+use std::rc::Rc;
+
+/// Everything reported during one round, in order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundRecord {
+    pub round: usize,
+    /// Opens with `GameEvent::RoundStart`; the final round also closes
+    /// with `GameEvent::GameOver`
+    pub events: Vec<GameEvent>,
+}
+
+/// A recorded game: the seed and rules, which are all it takes to play it
+/// again, and every round's events, to check the engine still does
+#[derive(Debug, Clone)]
+pub struct Replay {
+    /// `Deck::shuffle_with_seed` seed of the deal
+    pub seed: u64,
+    /// The rule settings the game was played under
+    pub config: GameConfig,
+    pub rounds: Vec<RoundRecord>,
+}
+
+impl Replay {
+    /// Play the seeded game silently, without prompts, and record it
+    pub fn record(seed: u64, config: GameConfig) -> GameResult<Replay> {
+        let config = GameConfig {
+            interactive: false,
+            hot_seat: false,
+            animate: false,
+            ..config
+        };
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(seed);
+        let mut game = WarGame::from_deck(deck, config, io::empty());
+        let recorder = ReplayRecorder::new(seed, config);
+        game.set_observer(recorder.clone());
+        game.play()?;
+        Ok(recorder.replay())
+    }
+
+    /// Every recorded event, first round first
+    pub fn events(&self) -> impl Iterator<Item = &GameEvent> {
+        self.rounds.iter().flat_map(|record| &record.events)
+    }
+
+    /// The first round the engine now plays differently than recorded, or
+    /// `None` if it reproduces the whole game
+    pub fn diverges_at(&self) -> GameResult<Option<usize>> {
+        let again = Replay::record(self.seed, self.config)?;
+        let mut recorded = self.rounds.iter();
+        let mut replayed = again.rounds.iter();
+        loop {
+            match (recorded.next(), replayed.next()) {
+                (None, None) => return Ok(None),
+                (Some(recorded), Some(replayed)) if recorded == replayed => {}
+                (recorded, replayed) => {
+                    return Ok(recorded.or(replayed).map(|record| record.round));
+                }
+            }
+        }
+    }
+
+    /// Render as a JSON object, one round per line
+    pub fn to_json(&self) -> String {
+        let rounds: Vec<String> = self
+            .rounds
+            .iter()
+            .map(|record| {
+                let events: Vec<String> =
+                    record.events.iter().map(GameEvent::to_log_json).collect();
+                format!(
+                    "    {{\"round\":{},\"events\":[{}]}}",
+                    record.round,
+                    events.join(",")
+                )
+            })
+            .collect();
+
+        let mut fields = vec![
+            ("seed", self.seed.to_string()),
+            ("players", self.config.players.to_string()),
+        ];
+        fields.extend(rules_json(&self.config));
+        fields.push(("rounds", format!("[\n{}\n  ]", rounds.join(",\n"))));
+        json_object(&fields)
+    }
+
+    /// Parse the output of `to_json`
+    pub fn from_json(text: &str) -> GameResult<Replay> {
+        let json = Json::parse(text)?;
+        let rounds = json
+            .field("rounds")?
+            .as_array()?
+            .iter()
+            .map(|record| {
+                Ok(RoundRecord {
+                    round: small(record.field("round")?.as_u64()?)?,
+                    events: record
+                        .field("events")?
+                        .as_array()?
+                        .iter()
+                        .map(GameEvent::from_json)
+                        .collect::<GameResult<_>>()?,
+                })
+            })
+            .collect::<GameResult<_>>()?;
+        let players = small(json.field("players")?.as_u64()?)?;
+        if !(2..=MAX_PLAYERS).contains(&players) {
+            return Err(invalid(format!(
+                "War needs 2 to {} players, got {}",
+                MAX_PLAYERS, players
+            )));
+        }
+
+        Ok(Replay {
+            seed: json.field("seed")?.as_u64()?,
+            config: rules_from_json(&json, players)?,
+            rounds,
+        })
+    }
+
+    /// Read a replay written with `save_to`
+    pub fn load_from(path: &Path) -> GameResult<Replay> {
+        Replay::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    /// Write the replay to `path` as JSON
+    pub fn save_to(&self, path: &Path) -> GameResult<()> {
+        std::fs::write(path, self.to_json())?;
+        Ok(())
+    }
+}
+
+/// Records a game as it is played, for replaying later
+/// Clones share one recording, so keep a clone to read it back with
+/// `replay` once the original is installed in a `WarGame`
+#[derive(Debug, Clone)]
+pub struct ReplayRecorder {
+    replay: Rc<RefCell<Replay>>,
+}
+
+impl ReplayRecorder {
+    /// Record a game dealt from a deck shuffled with `seed` and played under `config`
+    pub fn new(seed: u64, config: GameConfig) -> Self {
+        ReplayRecorder {
+            replay: Rc::new(RefCell::new(Replay {
+                seed,
+                config,
+                rounds: Vec::new(),
+            })),
+        }
+    }
+
+    /// The game as recorded so far
+    pub fn replay(&self) -> Replay {
+        self.replay.borrow().clone()
+    }
+
+    fn record(&self, event: GameEvent) {
+        let mut replay = self.replay.borrow_mut();
+        if let GameEvent::RoundStart { round, .. } = event {
+            replay.rounds.push(RoundRecord {
+                round,
+                events: Vec::new(),
+            });
+        }
+        if let Some(record) = replay.rounds.last_mut() {
+            record.events.push(event);
+        }
+    }
+}
+
+impl GameObserver for ReplayRecorder {
+    fn on_round_start(&mut self, round: usize, card_counts: &[usize]) {
+        self.record(GameEvent::RoundStart {
+            round,
+            card_counts: card_counts.to_vec(),
+        });
+    }
+
+    fn on_card_drawn(&mut self, player: usize, card: PlayedCard) {
+        self.record(GameEvent::CardDrawn { player, card });
+    }
+
+    fn on_card_burned(&mut self, player: usize, _number: usize, card: PlayedCard) {
+        self.record(GameEvent::CardDrawn { player, card });
+    }
+
+    fn on_war(&mut self, players: &[usize], pot_size: usize) {
+        self.record(GameEvent::War {
+            players: players.to_vec(),
+            pot_size,
+        });
+    }
+
+    fn on_round_won(&mut self, round: usize, winner: usize, pot_size: usize) {
+        self.record(GameEvent::RoundWon {
+            round,
+            winner,
+            pot_size,
+        });
+    }
+
+    fn on_game_over(&mut self, outcome: &GameOutcome, _stats: &GameStats) {
+        self.record(GameEvent::GameOver {
+            winner: outcome.winner,
+            rounds: outcome.rounds,
+            reason: outcome.reason,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::{Card, Rank, Suit};
+    use crate::game::EndReason;
+
+    #[test]
+    fn test_recorded_game_replays_identically() {
+        let config = GameConfig {
+            show_burns: true,
+            ..GameConfig::default()
+        };
+        let replay = Replay::record(3, config).unwrap();
+        assert_eq!(
+            replay.events().last(),
+            Some(&GameEvent::GameOver {
+                winner: Some(2),
+                rounds: replay.rounds.len(),
+                reason: EndReason::OutOfCards,
+            })
+        );
+
+        let reloaded = Replay::from_json(&replay.to_json()).unwrap();
+        assert_eq!(reloaded.seed, 3);
+        assert!(reloaded.config.show_burns);
+        assert_eq!(reloaded.rounds, replay.rounds);
+        assert!(reloaded.events().eq(replay.events()));
+        assert_eq!(reloaded.diverges_at().unwrap(), None);
+    }
+
+    #[test]
+    fn test_tampered_replay_diverges_at_the_changed_round() {
+        let mut replay = Replay::record(3, GameConfig::default()).unwrap();
+        let record = &mut replay.rounds[9];
+        for event in &mut record.events {
+            if let GameEvent::CardDrawn { card, .. } = event {
+                card.card = Card::new(Suit::Hearts, Rank::Two);
+            }
+        }
+
+        assert_eq!(replay.diverges_at().unwrap(), Some(10));
+    }
+}
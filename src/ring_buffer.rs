@@ -1,25 +1,41 @@
+use std::fmt;
+use std::mem::{self, MaybeUninit};
+
 /// A fixed-size ring buffer implementation using stack allocation
 /// Generic over type T and size N for compile-time size guarantees
-#[derive(Debug, Clone)]
-pub struct RingBuffer<T: Copy, const N: usize> {
-    buffer: [T; N],
+///
+/// Slots are `MaybeUninit`, so no placeholder value is needed and `T` need not
+/// be `Copy`. Invariant: exactly the `count` slots from `tail` onwards (wrapping)
+/// are initialized; every other slot is uninitialized. Methods that hand out
+/// elements by value (`front`, `get`, `iter`, ...) still require `T: Copy`
+pub struct RingBuffer<T, const N: usize> {
+    buffer: [MaybeUninit<T>; N],
     head: usize,  // Points to the next position to write
     tail: usize,  // Points to the next position to read
     count: usize, // Number of elements currently in buffer
 }
 
-impl<T: Copy, const N: usize> RingBuffer<T, N> {
-    #[allow(dead_code)]
-    /// Create a new empty ring buffer with a default value for initialization
-    pub fn new(default_value: T) -> Self {
+impl<T, const N: usize> RingBuffer<T, N> {
+    /// Create a new empty ring buffer
+    pub const fn new() -> Self {
         Self {
-            buffer: [default_value; N],
+            buffer: [const { MaybeUninit::uninit() }; N],
             head: 0,
             tail: 0,
             count: 0,
         }
     }
 
+    /// Create a full ring buffer whose contents are the array, front to back
+    pub fn from_array(arr: [T; N]) -> Self {
+        Self {
+            buffer: arr.map(MaybeUninit::new),
+            head: 0, // N wraps back around to 0
+            tail: 0,
+            count: N,
+        }
+    }
+
     /// Returns the number of elements in the buffer
     pub fn len(&self) -> usize {
         self.count
@@ -41,19 +57,37 @@ impl<T: Copy, const N: usize> RingBuffer<T, N> {
         N
     }
 
+    /// Returns true if `n` more elements can be pushed before the buffer is full
+    pub fn can_fit(&self, n: usize) -> bool {
+        n <= N - self.count
+    }
+
     /// Push an element to the back of the buffer
-    /// Returns true if successful, false if buffer is full
+    /// Returns true if successful, false if buffer is full (dropping `item`)
     pub fn push_back(&mut self, item: T) -> bool {
         if self.is_full() {
             return false;
         }
 
-        self.buffer[self.head] = item;
+        self.buffer[self.head] = MaybeUninit::new(item);
         self.head = (self.head + 1) % N;
         self.count += 1;
         true
     }
 
+    /// Push an element to the back, evicting the front element if the buffer
+    /// is full, so the buffer keeps the newest `N` items like a circular log
+    /// Returns the evicted element, or None if there was room
+    pub fn push_back_overwrite(&mut self, item: T) -> Option<T> {
+        let evicted = if self.is_full() {
+            self.pop_front()
+        } else {
+            None
+        };
+        self.push_back(item);
+        evicted
+    }
+
     /// Pop an element from the front of the buffer
     /// Returns Some(T) if successful, None if buffer is empty
     #[allow(dead_code)]
@@ -62,21 +96,21 @@ impl<T: Copy, const N: usize> RingBuffer<T, N> {
             return None;
         }
 
-        let item = self.buffer[self.tail];
+        let item = self.take_slot(self.tail);
         self.tail = (self.tail + 1) % N;
         self.count -= 1;
         Some(item)
     }
 
     /// Push an element to the front of the buffer (prepend)
-    /// Returns true if successful, false if buffer is full
+    /// Returns true if successful, false if buffer is full (dropping `item`)
     pub fn push_front(&mut self, item: T) -> bool {
         if self.is_full() {
             return false;
         }
 
         self.tail = if self.tail == 0 { N - 1 } else { self.tail - 1 };
-        self.buffer[self.tail] = item;
+        self.buffer[self.tail] = MaybeUninit::new(item);
         self.count += 1;
         true
     }
@@ -89,11 +123,168 @@ impl<T: Copy, const N: usize> RingBuffer<T, N> {
         }
 
         self.head = if self.head == 0 { N - 1 } else { self.head - 1 };
-        let item = self.buffer[self.head];
+        let item = self.take_slot(self.head);
+        self.count -= 1;
+        Some(item)
+    }
+
+    /// Insert an element so it ends up `index` positions from the front,
+    /// shifting later elements back by one
+    /// Returns false if the buffer is full or `index > len()`
+    pub fn insert(&mut self, index: usize, item: T) -> bool {
+        if self.is_full() || index > self.count {
+            return false;
+        }
+
+        for i in (index..self.count).rev() {
+            self.move_slot(self.physical(i), self.physical(i + 1));
+        }
+        self.buffer[self.physical(index)] = MaybeUninit::new(item);
+        self.head = (self.head + 1) % N;
+        self.count += 1;
+        true
+    }
+
+    /// Remove and return the element `index` positions from the front,
+    /// shifting later elements forward by one
+    /// Returns None if `index >= len()`
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.count {
+            return None;
+        }
+
+        let item = self.take_slot(self.physical(index));
+        for i in index..self.count - 1 {
+            self.move_slot(self.physical(i + 1), self.physical(i));
+        }
+        self.head = if self.head == 0 { N - 1 } else { self.head - 1 };
         self.count -= 1;
         Some(item)
     }
 
+    /// Borrow the element `index` positions from the front
+    /// Returns None if `index >= len()`
+    pub fn get_ref(&self, index: usize) -> Option<&T> {
+        // SAFETY: logical indices below `count` are initialized
+        (index < self.count).then(|| unsafe { self.buffer[self.physical(index)].assume_init_ref() })
+    }
+
+    /// Returns true if any live element equals `item`
+    pub fn contains(&self, item: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let (first, second) = self.as_slices();
+        first.contains(item) || second.contains(item)
+    }
+
+    /// Index from the front of the first live element matching `pred`
+    pub fn position<P: FnMut(&T) -> bool>(&self, pred: P) -> Option<usize> {
+        let (first, second) = self.as_slices();
+        first.iter().chain(second).position(pred)
+    }
+
+    /// The live elements as at most two contiguous slices, front to back
+    /// The second slice holds whatever wrapped past the end of the backing
+    /// array, and is empty when the contents are contiguous
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let first_len = self.count.min(N - self.tail);
+        let first = &self.buffer[self.tail..self.tail + first_len];
+        let second = &self.buffer[..self.count - first_len];
+        // SAFETY: both ranges cover only the `count` initialized slots, and
+        // `MaybeUninit<T>` has the same layout as `T`
+        unsafe {
+            (
+                &*(first as *const [MaybeUninit<T>] as *const [T]),
+                &*(second as *const [MaybeUninit<T>] as *const [T]),
+            )
+        }
+    }
+
+    /// Mutable counterpart of `as_slices`
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let first_len = self.count.min(N - self.tail);
+        let second_len = self.count - first_len;
+        let (wrapped, from_tail) = self.buffer.split_at_mut(self.tail);
+        let first = &mut from_tail[..first_len];
+        let second = &mut wrapped[..second_len];
+        // SAFETY: as in `as_slices`; the two ranges come from `split_at_mut`,
+        // so they never overlap
+        unsafe {
+            (
+                &mut *(first as *mut [MaybeUninit<T>] as *mut [T]),
+                &mut *(second as *mut [MaybeUninit<T>] as *mut [T]),
+            )
+        }
+    }
+
+    /// Mutably borrow the element `index` positions from the front
+    /// Returns None if `index >= len()`
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        // SAFETY: logical indices below `count` are initialized
+        (index < self.count).then(|| unsafe { self.buffer[self.physical(index)].assume_init_mut() })
+    }
+
+    /// Iterate mutably over the elements in order (front to back)
+    pub fn iter_mut(&mut self) -> RingBufferIterMut<'_, T> {
+        let (first, second) = self.as_mut_slices();
+        RingBufferIterMut {
+            inner: first.iter_mut().chain(second.iter_mut()),
+        }
+    }
+
+    /// Map a front-relative logical index to a position in the backing array
+    fn physical(&self, index: usize) -> usize {
+        (self.tail + index) % N
+    }
+
+    /// Move the value out of an initialized slot, leaving it uninitialized
+    /// The caller must then treat the slot as dead (adjusting `count` or
+    /// refilling it)
+    fn take_slot(&mut self, position: usize) -> T {
+        let slot = mem::replace(&mut self.buffer[position], MaybeUninit::uninit());
+        // SAFETY: callers only take slots that hold live elements
+        unsafe { slot.assume_init() }
+    }
+
+    /// Move a slot's contents to another position without reading them
+    fn move_slot(&mut self, from: usize, to: usize) {
+        self.buffer[to] = mem::replace(&mut self.buffer[from], MaybeUninit::uninit());
+    }
+
+    /// Pop fixed-size arrays off the front while at least `M` items remain
+    /// Any remainder shorter than `M` (and any chunk not yet yielded if the
+    /// iterator is dropped early) stays in the buffer
+    pub fn drain_chunks<const M: usize>(&mut self) -> impl Iterator<Item = [T; M]> + '_ {
+        std::iter::from_fn(move || {
+            if M == 0 || self.len() < M {
+                return None;
+            }
+            Some(std::array::from_fn(|_| {
+                self.pop_front().expect("length checked above")
+            }))
+        })
+    }
+
+    /// Pop every element off the front, in order
+    /// The buffer is left empty even if the iterator is dropped early; any
+    /// elements not yet yielded are dropped with it
+    pub fn drain(&mut self) -> Drain<'_, T, N> {
+        Drain { buffer: self }
+    }
+
+    /// Clear all elements from the buffer, dropping them
+    pub fn clear(&mut self) {
+        if mem::needs_drop::<T>() {
+            while self.pop_front().is_some() {}
+        }
+        self.head = 0;
+        self.tail = 0;
+        self.count = 0;
+    }
+}
+
+impl<T: Copy, const N: usize> RingBuffer<T, N> {
     /// Add multiple items to the front of the buffer (useful for winning cards in War)
     /// Items are added in reverse order so the first item in the slice becomes the front
     /// Returns the number of items successfully added
@@ -110,6 +301,16 @@ impl<T: Copy, const N: usize> RingBuffer<T, N> {
         added
     }
 
+    /// Prepend all of `items` (keeping their order) or none of them
+    /// On failure the buffer is untouched and `items` is handed back
+    pub fn try_push_front_all<'a>(&mut self, items: &'a [T]) -> Result<(), &'a [T]> {
+        if !self.can_fit(items.len()) {
+            return Err(items);
+        }
+        self.push_front_multiple(items);
+        Ok(())
+    }
+
     /// Add multiple items to the back of the buffer
     /// Returns the number of items successfully added
     #[allow(dead_code)]
@@ -125,79 +326,274 @@ impl<T: Copy, const N: usize> RingBuffer<T, N> {
         added
     }
 
+    /// Append all of `items` (keeping their order) or none of them
+    /// All-or-nothing: if they don't all fit the buffer is untouched, and the
+    /// error holds how many would have fit, which is what
+    /// `push_back_multiple` would have added before stopping
+    pub fn try_extend(&mut self, items: &[T]) -> Result<(), usize> {
+        if !self.can_fit(items.len()) {
+            return Err(N - self.count);
+        }
+        self.push_back_multiple(items);
+        Ok(())
+    }
+
+    /// Peek at the element `index` positions from the front
+    /// Returns None if `index >= len()`
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.get_ref(index).copied()
+    }
+
+    /// Peek at the element `index` positions from the back (0 is the back)
+    /// Returns None if `index >= len()`
+    pub fn get_back(&self, index: usize) -> Option<T> {
+        self.get(self.count.checked_sub(index + 1)?)
+    }
+
     /// Peek at the front element without removing it
     #[allow(dead_code)]
     pub fn front(&self) -> Option<T> {
-        if self.is_empty() {
-            None
-        } else {
-            Some(self.buffer[self.tail])
-        }
+        self.get(0)
     }
 
     /// Peek at the back element without removing it
     #[allow(dead_code)]
     pub fn back(&self) -> Option<T> {
-        if self.is_empty() {
-            None
-        } else {
-            let back_idx = if self.head == 0 { N - 1 } else { self.head - 1 };
-            Some(self.buffer[back_idx])
-        }
+        self.get_back(0)
     }
 
-    /// Clear all elements from the buffer
-    pub fn clear(&mut self) {
-        self.head = 0;
-        self.tail = 0;
-        self.count = 0;
+    /// Peek at both ends as `(front, back)`; a single element is both
+    pub fn ends(&self) -> Option<(T, T)> {
+        Some((self.front()?, self.back()?))
+    }
+
+    /// Returns true if the live elements, front to back, equal the slice
+    pub fn eq_slice(&self, other: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.count == other.len() && self.iter().zip(other).all(|(a, b)| a == *b)
     }
 
     /// Create an iterator over the elements in order (front to back)
-    pub fn iter(&self) -> RingBufferIter<T, N> {
+    pub fn iter(&self) -> RingBufferIter<'_, T, N> {
         RingBufferIter {
             buffer: self,
-            current: self.tail,
-            remaining: self.count,
+            index: 0,
+        }
+    }
+}
+
+/// An empty buffer, like `new`; slots start uninitialized, so `T` needs
+/// no `Default` of its own
+impl<T, const N: usize> Default for RingBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Collects front to back; panics if the iterator yields more than `N` items
+/// (use `TryFrom<&[T]>` to check a slice first)
+impl<T, const N: usize> FromIterator<T> for RingBuffer<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut buffer = Self::new();
+        for item in iter {
+            assert!(
+                buffer.push_back(item),
+                "more than {} items collected into a RingBuffer",
+                N
+            );
         }
+        buffer
+    }
+}
+
+/// Copies the slice front to back, or hands it back if it's longer than `N`
+impl<'a, T: Copy, const N: usize> TryFrom<&'a [T]> for RingBuffer<T, N> {
+    type Error = &'a [T];
+
+    fn try_from(items: &'a [T]) -> Result<Self, Self::Error> {
+        let mut buffer = Self::new();
+        buffer.try_push_front_all(items)?;
+        Ok(buffer)
+    }
+}
+
+impl<T, const N: usize> Drop for RingBuffer<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+/// Clones the live elements only, into the same slots
+impl<T: Clone, const N: usize> Clone for RingBuffer<T, N> {
+    fn clone(&self) -> Self {
+        let mut clone = Self::new();
+        for i in 0..self.count {
+            let position = self.physical(i);
+            // SAFETY: logical indices below `count` are initialized
+            let item = unsafe { self.buffer[position].assume_init_ref() };
+            clone.buffer[position] = MaybeUninit::new(item.clone());
+        }
+        clone.head = self.head;
+        clone.tail = self.tail;
+        clone.count = self.count;
+        clone
+    }
+}
+
+/// Compares the live elements front to back, ignoring where they sit in the
+/// backing array
+impl<T: PartialEq, const N: usize> PartialEq for RingBuffer<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.count == other.count && (0..self.count).all(|i| self.get_ref(i) == other.get_ref(i))
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for RingBuffer<T, N> {}
+
+/// Lists the live elements, front to back
+impl<T: fmt::Debug, const N: usize> fmt::Debug for RingBuffer<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries((0..self.count).filter_map(|i| self.get_ref(i)))
+            .finish()
     }
 }
 
 /// Iterator for RingBuffer
 pub struct RingBufferIter<'a, T: Copy, const N: usize> {
     buffer: &'a RingBuffer<T, N>,
-    current: usize,
-    remaining: usize,
+    index: usize,
 }
 
 impl<'a, T: Copy, const N: usize> Iterator for RingBufferIter<'a, T, N> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.remaining == 0 {
-            return None;
-        }
-
-        let item = self.buffer.buffer[self.current];
-        self.current = (self.current + 1) % N;
-        self.remaining -= 1;
+        let item = self.buffer.get(self.index)?;
+        self.index += 1;
         Some(item)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.remaining, Some(self.remaining))
+        let remaining = self.buffer.len() - self.index;
+        (remaining, Some(remaining))
     }
 }
 
 impl<'a, T: Copy, const N: usize> ExactSizeIterator for RingBufferIter<'a, T, N> {}
 
+/// Mutable iterator for RingBuffer, front to back
+pub struct RingBufferIterMut<'a, T> {
+    inner: std::iter::Chain<std::slice::IterMut<'a, T>, std::slice::IterMut<'a, T>>,
+}
+
+impl<'a, T> Iterator for RingBufferIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for RingBufferIterMut<'a, T> {}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut RingBuffer<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = RingBufferIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<'a, T: Copy, const N: usize> IntoIterator for &'a RingBuffer<T, N> {
+    type Item = T;
+    type IntoIter = RingBufferIter<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Owning iterator for RingBuffer, popping elements front to back
+/// Elements not yet yielded are dropped with the iterator
+pub struct RingBufferIntoIter<T, const N: usize> {
+    buffer: RingBuffer<T, N>,
+}
+
+impl<T, const N: usize> Iterator for RingBufferIntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buffer.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.buffer.len(), Some(self.buffer.len()))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for RingBufferIntoIter<T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.buffer.pop_back()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for RingBufferIntoIter<T, N> {}
+
+/// Draining iterator for RingBuffer, created by `RingBuffer::drain`
+pub struct Drain<'a, T, const N: usize> {
+    buffer: &'a mut RingBuffer<T, N>,
+}
+
+impl<T, const N: usize> Iterator for Drain<'_, T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buffer.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.buffer.len(), Some(self.buffer.len()))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for Drain<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.buffer.pop_back()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Drain<'_, T, N> {}
+
+impl<T, const N: usize> Drop for Drain<'_, T, N> {
+    fn drop(&mut self) {
+        self.buffer.clear();
+    }
+}
+
+impl<T, const N: usize> IntoIterator for RingBuffer<T, N> {
+    type Item = T;
+    type IntoIter = RingBufferIntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        RingBufferIntoIter { buffer: self }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_basic_operations() {
-        let mut rb = RingBuffer::<i32, 5>::new(0);
+        let mut rb = RingBuffer::<i32, 5>::new();
 
         assert!(rb.is_empty());
         assert_eq!(rb.len(), 0);
@@ -220,7 +616,7 @@ mod tests {
 
     #[test]
     fn test_front_operations() {
-        let mut rb = RingBuffer::<i32, 4>::new(0);
+        let mut rb = RingBuffer::<i32, 4>::new();
 
         assert!(rb.push_front(1));
         assert!(rb.push_front(2));
@@ -233,7 +629,7 @@ mod tests {
 
     #[test]
     fn test_wraparound() {
-        let mut rb = RingBuffer::<i32, 3>::new(0);
+        let mut rb = RingBuffer::<i32, 3>::new();
 
         // Fill the buffer
         assert!(rb.push_back(1));
@@ -255,7 +651,7 @@ mod tests {
 
     #[test]
     fn test_multiple_operations() {
-        let mut rb = RingBuffer::<i32, 10>::new(0);
+        let mut rb = RingBuffer::<i32, 10>::new();
 
         let items = vec![1, 2, 3, 4, 5];
         assert_eq!(rb.push_back_multiple(&items), 5);
@@ -271,19 +667,46 @@ mod tests {
         assert_eq!(rb.pop_front(), Some(1));
     }
 
+    #[test]
+    fn test_default_is_empty() {
+        let rb = RingBuffer::<i32, 8>::default();
+        assert!(rb.is_empty());
+        assert_eq!(rb.capacity(), 8);
+
+        // No `Default` needed for the element type
+        struct NoDefault;
+        assert!(RingBuffer::<NoDefault, 2>::default().is_empty());
+    }
+
     #[test]
     fn test_capacity() {
-        let rb = RingBuffer::<i32, 10>::new(0);
+        let rb = RingBuffer::<i32, 10>::new();
         assert_eq!(rb.capacity(), 10);
         assert_eq!(rb.len(), 0);
 
-        let rb2 = RingBuffer::<i32, 5>::new(0);
+        let rb2 = RingBuffer::<i32, 5>::new();
         assert_eq!(rb2.capacity(), 5);
     }
 
+    #[test]
+    fn test_can_fit_boundaries() {
+        let mut rb = RingBuffer::<i32, 4>::new();
+        assert!(rb.can_fit(4));
+        assert!(!rb.can_fit(5));
+
+        rb.push_back_multiple(&[1, 2, 3]);
+        assert!(rb.can_fit(0));
+        assert!(rb.can_fit(1));
+        assert!(!rb.can_fit(2));
+
+        rb.push_back(4);
+        assert!(rb.can_fit(0));
+        assert!(!rb.can_fit(1));
+    }
+
     #[test]
     fn test_pop_front() {
-        let mut rb = RingBuffer::<i32, 4>::new(0);
+        let mut rb = RingBuffer::<i32, 4>::new();
 
         // Test empty
         assert_eq!(rb.pop_front(), None);
@@ -302,7 +725,7 @@ mod tests {
 
     #[test]
     fn test_push_front_multiple() {
-        let mut rb = RingBuffer::<i32, 10>::new(0);
+        let mut rb = RingBuffer::<i32, 10>::new();
 
         // Add some initial items
         assert!(rb.push_back(1));
@@ -324,7 +747,7 @@ mod tests {
 
     #[test]
     fn test_push_back_multiple() {
-        let mut rb = RingBuffer::<i32, 10>::new(0);
+        let mut rb = RingBuffer::<i32, 10>::new();
 
         // Add some initial items
         assert!(rb.push_front(1));
@@ -345,7 +768,7 @@ mod tests {
 
     #[test]
     fn test_front_and_back() {
-        let mut rb = RingBuffer::<i32, 5>::new(0);
+        let mut rb = RingBuffer::<i32, 5>::new();
 
         // Test empty
         assert_eq!(rb.front(), None);
@@ -370,7 +793,7 @@ mod tests {
 
     #[test]
     fn test_overflow_behavior() {
-        let mut rb = RingBuffer::<i32, 3>::new(0);
+        let mut rb = RingBuffer::<i32, 3>::new();
 
         // Fill to capacity
         assert_eq!(rb.push_front_multiple(&[1, 2, 3]), 3);
@@ -388,4 +811,409 @@ mod tests {
         assert_eq!(rb.len(), 3);
         assert!(rb.is_full());
     }
+
+    #[test]
+    fn test_from_array() {
+        let mut rb = RingBuffer::from_array([1, 2, 3]);
+
+        assert!(rb.is_full());
+        assert_eq!(rb.len(), 3);
+        assert_eq!(rb.capacity(), 3);
+        assert_eq!(rb.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(rb.front(), Some(1));
+        assert_eq!(rb.back(), Some(3));
+
+        // Wraparound still works after popping
+        assert_eq!(rb.pop_front(), Some(1));
+        assert!(rb.push_back(4));
+        assert_eq!(rb.iter().collect::<Vec<_>>(), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_eq_slice() {
+        let mut rb = RingBuffer::<i32, 4>::new();
+        assert!(rb.eq_slice(&[]));
+
+        // Wrap the contents around the end of the storage
+        rb.push_back_multiple(&[0, 0, 1]);
+        rb.pop_front();
+        rb.pop_front();
+        rb.push_back_multiple(&[2, 3]);
+
+        assert!(rb.eq_slice(&[1, 2, 3]));
+        assert!(!rb.eq_slice(&[1, 2, 4]));
+        assert!(!rb.eq_slice(&[3, 2, 1]));
+        // Length mismatches
+        assert!(!rb.eq_slice(&[1, 2]));
+        assert!(!rb.eq_slice(&[1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut rb = RingBuffer::<i32, 6>::new();
+        rb.push_back_multiple(&[1, 2, 4, 5]);
+
+        assert!(rb.insert(2, 3));
+        assert!(rb.eq_slice(&[1, 2, 3, 4, 5]));
+        assert!(rb.insert(0, 0));
+        assert!(rb.eq_slice(&[0, 1, 2, 3, 4, 5]));
+
+        // Full buffer rejects inserts
+        assert!(!rb.insert(3, 99));
+
+        assert_eq!(rb.remove(3), Some(3));
+        assert!(rb.eq_slice(&[0, 1, 2, 4, 5]));
+        assert_eq!(rb.remove(4), Some(5));
+        assert_eq!(rb.remove(0), Some(0));
+        assert!(rb.eq_slice(&[1, 2, 4]));
+        assert_eq!(rb.back(), Some(4));
+    }
+
+    #[test]
+    fn test_insert_and_remove_wrapped() {
+        let mut rb = RingBuffer::<i32, 5>::new();
+        rb.push_back_multiple(&[0, 0, 0, 10, 20]);
+        rb.pop_front();
+        rb.pop_front();
+        rb.pop_front();
+        rb.push_back(40); // tail > head now
+
+        assert!(rb.insert(2, 30));
+        assert!(rb.eq_slice(&[10, 20, 30, 40]));
+        // Inserting at len() appends
+        assert!(rb.insert(4, 50));
+        assert!(rb.eq_slice(&[10, 20, 30, 40, 50]));
+
+        assert_eq!(rb.remove(1), Some(20));
+        assert!(rb.eq_slice(&[10, 30, 40, 50]));
+        assert!(rb.push_back(60));
+        assert!(rb.eq_slice(&[10, 30, 40, 50, 60]));
+    }
+
+    #[test]
+    fn test_insert_and_remove_out_of_range() {
+        let mut rb = RingBuffer::<i32, 4>::new();
+        rb.push_back_multiple(&[1, 2]);
+
+        assert!(!rb.insert(3, 9));
+        assert_eq!(rb.remove(2), None);
+        assert!(rb.eq_slice(&[1, 2]));
+    }
+
+    #[test]
+    fn test_ends() {
+        let mut rb = RingBuffer::<i32, 4>::new();
+        assert_eq!(rb.ends(), None);
+
+        rb.push_back(7);
+        assert_eq!(rb.ends(), Some((7, 7)));
+
+        rb.push_back_multiple(&[8, 9, 10]);
+        rb.pop_front();
+        rb.push_back(11);
+        assert_eq!(rb.ends(), Some((8, 11)));
+    }
+
+    #[test]
+    fn test_try_push_front_all_is_all_or_nothing() {
+        let mut rb = RingBuffer::<i32, 5>::new();
+        rb.push_back_multiple(&[4, 5]);
+
+        let too_many = [0, 1, 2, 3];
+        assert_eq!(rb.try_push_front_all(&too_many), Err(&too_many[..]));
+        assert!(rb.eq_slice(&[4, 5]));
+
+        assert_eq!(rb.try_push_front_all(&[1, 2, 3]), Ok(()));
+        assert!(rb.eq_slice(&[1, 2, 3, 4, 5]));
+        assert_eq!(rb.try_push_front_all(&[]), Ok(()));
+    }
+
+    #[test]
+    fn test_try_extend_is_all_or_nothing() {
+        let mut rb = RingBuffer::<i32, 5>::new();
+        rb.push_back_multiple(&[0, 0, 1]);
+        rb.pop_front();
+        rb.pop_front();
+
+        // Exact fit, wrapping past the end of the backing array
+        assert_eq!(rb.try_extend(&[2, 3, 4, 5]), Ok(()));
+        assert!(rb.is_full());
+        assert!(rb.eq_slice(&[1, 2, 3, 4, 5]));
+        assert_eq!(rb.try_extend(&[]), Ok(()));
+
+        rb.pop_front();
+        rb.pop_front();
+        assert_eq!(rb.try_extend(&[6, 7, 8]), Err(2));
+        assert!(rb.eq_slice(&[3, 4, 5]));
+        assert_eq!(rb.try_extend(&[6, 7]), Ok(()));
+        assert!(rb.eq_slice(&[3, 4, 5, 6, 7]));
+    }
+
+    #[test]
+    fn test_drain_chunks_leaves_remainder() {
+        let mut rb = RingBuffer::<i32, 8>::new();
+        rb.push_back_multiple(&[1, 2, 3, 4, 5, 6, 7]);
+
+        let pairs: Vec<[i32; 2]> = rb.drain_chunks::<2>().collect();
+        assert_eq!(pairs, vec![[1, 2], [3, 4], [5, 6]]);
+        assert!(rb.eq_slice(&[7]));
+
+        assert_eq!(rb.drain_chunks::<0>().count(), 0);
+        assert!(rb.eq_slice(&[7]));
+    }
+
+    #[test]
+    fn test_drain_empties_the_buffer() {
+        let mut rb = RingBuffer::<i32, 4>::new();
+        rb.push_back_multiple(&[0, 0, 1, 2]);
+        rb.pop_front();
+        rb.pop_front();
+        rb.push_back_multiple(&[3, 4]);
+
+        let drained: Vec<i32> = rb.drain().collect();
+        assert_eq!(drained, vec![1, 2, 3, 4]);
+        assert!(rb.is_empty());
+
+        // Reusable afterwards, like any emptied buffer
+        rb.push_back_multiple(&[5, 6, 7]);
+        let mut drain = rb.drain();
+        assert_eq!(drain.len(), 3);
+        assert_eq!(drain.next(), Some(5));
+        assert_eq!(drain.next_back(), Some(7));
+        drop(drain);
+        assert!(rb.is_empty());
+        assert_eq!(rb.drain().next(), None);
+    }
+
+    #[test]
+    fn test_contains_and_position_skip_popped_slots() {
+        let mut rb = RingBuffer::<i32, 4>::new();
+        rb.push_back_multiple(&[1, 2, 3, 4]);
+        rb.pop_front();
+        rb.pop_front();
+        rb.push_back_multiple(&[5, 6]);
+        rb.pop_back();
+
+        // Live: [3, 4, 5]; 1 and 6 were popped from slots that are now dead
+        assert!(rb.contains(&5));
+        assert!(!rb.contains(&1));
+        assert!(!rb.contains(&6));
+        assert_eq!(rb.position(|&x| x == 3), Some(0));
+        assert_eq!(rb.position(|&x| x == 5), Some(2));
+        assert_eq!(rb.position(|&x| x > 4), Some(2));
+        assert_eq!(rb.position(|&x| x == 2), None);
+        assert_eq!(rb.position(|&x| x == 6), None);
+    }
+
+    #[test]
+    fn test_get_on_wrapped_buffer() {
+        let mut rb = RingBuffer::<i32, 4>::new();
+        rb.push_back_multiple(&[0, 0, 0, 1]);
+        rb.pop_front();
+        rb.pop_front();
+        rb.pop_front();
+        rb.push_back_multiple(&[2, 3]); // tail > head now
+
+        assert_eq!(rb.get(0), Some(1));
+        assert_eq!(rb.get(1), Some(2));
+        assert_eq!(rb.get(2), Some(3));
+        assert_eq!(rb.get(3), None);
+
+        assert_eq!(rb.get_back(0), Some(3));
+        assert_eq!(rb.get_back(2), Some(1));
+        assert_eq!(rb.get_back(3), None);
+
+        assert_eq!(RingBuffer::<i32, 4>::new().get(0), None);
+    }
+
+    #[test]
+    fn test_owned_payloads() {
+        let mut rb = RingBuffer::<String, 4>::new();
+        for word in ["war", "is", "hell"] {
+            assert!(rb.push_back(word.to_string()));
+        }
+        assert!(rb.insert(1, "really".to_string()));
+        assert_eq!(rb.get_ref(1).map(String::as_str), Some("really"));
+
+        let copy = rb.clone();
+        assert_eq!(rb.remove(2), Some("is".to_string()));
+        assert_eq!(rb.pop_back(), Some("hell".to_string()));
+        assert_eq!(rb.pop_front(), Some("war".to_string()));
+        assert_eq!(format!("{:?}", rb), r#"["really"]"#);
+        assert_eq!(format!("{:?}", copy), r#"["war", "really", "is", "hell"]"#);
+    }
+
+    #[test]
+    fn test_every_element_is_dropped_exactly_once() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        struct Tracked(Rc<Cell<usize>>);
+        impl Drop for Tracked {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Rc::new(Cell::new(0));
+        let tracked = || Tracked(Rc::clone(&drops));
+        {
+            let mut rb = RingBuffer::<Tracked, 4>::new();
+            for _ in 0..4 {
+                rb.push_back(tracked());
+            }
+            // A push into a full buffer drops the rejected item
+            assert!(!rb.push_front(tracked()));
+            assert_eq!(drops.get(), 1);
+
+            drop(rb.pop_front());
+            drop(rb.remove(1));
+            assert_eq!(drops.get(), 3);
+
+            rb.clear();
+            assert_eq!(drops.get(), 5);
+            assert!(rb.is_empty());
+
+            // Dropping a drain early drops whatever it didn't yield
+            rb.push_back(tracked());
+            rb.push_back(tracked());
+            let first = rb.drain().next();
+            assert_eq!(drops.get(), 6);
+            drop(first);
+            assert_eq!(drops.get(), 7);
+            assert!(rb.is_empty());
+
+            // Wrap around before the buffer itself goes out of scope
+            for _ in 0..3 {
+                rb.push_front(tracked());
+            }
+        }
+        assert_eq!(drops.get(), 10);
+    }
+
+    #[test]
+    fn test_for_loops_over_borrowed_and_owned_buffers() {
+        let mut rb = RingBuffer::<i32, 4>::new();
+        rb.push_back_multiple(&[0, 1, 2]);
+        rb.pop_front();
+        rb.push_back_multiple(&[3, 4]); // wrapped
+        let expected: Vec<i32> = rb.iter().collect();
+
+        let mut borrowed = Vec::new();
+        for item in &rb {
+            borrowed.push(item);
+        }
+        assert_eq!(borrowed, expected);
+
+        let mut owned = Vec::new();
+        for item in rb.clone() {
+            owned.push(item);
+        }
+        assert_eq!(owned, expected);
+        assert_eq!(rb.into_iter().rev().collect::<Vec<_>>(), [4, 3, 2, 1]);
+
+        let words = RingBuffer::<String, 2>::from_array(["a".to_string(), "b".to_string()]);
+        assert_eq!(words.into_iter().collect::<Vec<_>>(), ["a", "b"]);
+    }
+
+    #[test]
+    fn test_collect_and_try_from() {
+        let exact: RingBuffer<i32, 3> = (1..=3).collect();
+        assert!(exact.is_full() && exact.eq_slice(&[1, 2, 3]));
+        let under: RingBuffer<i32, 3> = (1..=2).collect();
+        assert!(under.eq_slice(&[1, 2]));
+
+        let overflow = std::panic::catch_unwind(|| (1..=4).collect::<RingBuffer<i32, 3>>());
+        assert!(overflow.is_err());
+
+        let exact = RingBuffer::<i32, 3>::try_from(&[1, 2, 3][..]).unwrap();
+        assert!(exact.eq_slice(&[1, 2, 3]));
+        let under = RingBuffer::<i32, 3>::try_from(&[7][..]).unwrap();
+        assert!(under.eq_slice(&[7]));
+        let long = [1, 2, 3, 4];
+        assert_eq!(
+            RingBuffer::<i32, 3>::try_from(&long[..]).unwrap_err(),
+            &long[..]
+        );
+    }
+
+    #[test]
+    fn test_push_back_overwrite_keeps_the_newest() {
+        let mut rb = RingBuffer::<i32, 4>::new();
+        let evicted: Vec<i32> = (1..=7).filter_map(|i| rb.push_back_overwrite(i)).collect();
+
+        assert_eq!(evicted, [1, 2, 3]);
+        assert!(rb.eq_slice(&[4, 5, 6, 7]));
+        assert!(!rb.push_back(8));
+    }
+
+    #[test]
+    fn test_equality_ignores_rotation() {
+        let straight: RingBuffer<i32, 5> = [1, 2, 3].into_iter().collect();
+
+        let mut rotated = RingBuffer::<i32, 5>::new();
+        rotated.push_back_multiple(&[0, 0, 0, 0, 1]);
+        for _ in 0..4 {
+            rotated.pop_front();
+        }
+        rotated.push_back_multiple(&[2, 3]); // wraps past the end of the array
+
+        assert_eq!(straight, rotated);
+        rotated.pop_back();
+        assert_ne!(straight, rotated);
+        rotated.push_back(4);
+        assert_ne!(straight, rotated);
+    }
+
+    #[test]
+    fn test_as_slices() {
+        let mut rb = RingBuffer::<i32, 5>::new();
+        assert_eq!(rb.as_slices(), (&[][..], &[][..]));
+
+        rb.push_back_multiple(&[1, 2, 3]);
+        assert_eq!(rb.as_slices(), (&[1, 2, 3][..], &[][..]));
+
+        rb.pop_front();
+        rb.pop_front();
+        rb.push_back_multiple(&[4, 5, 6, 7]); // wraps after 5
+        let (front, back) = rb.as_slices();
+        assert_eq!((front, back), (&[3, 4, 5][..], &[6, 7][..]));
+        assert_eq!([front, back].concat(), rb.iter().collect::<Vec<_>>());
+
+        // Full and starting exactly at the end of the array
+        let mut edge = RingBuffer::<i32, 3>::from_array([0, 0, 1]);
+        edge.pop_front();
+        edge.pop_front();
+        edge.push_back_multiple(&[2, 3]);
+        assert_eq!(edge.as_slices(), (&[1][..], &[2, 3][..]));
+    }
+
+    #[test]
+    fn test_iter_mut_and_get_mut_on_wrapped_buffer() {
+        let mut rb = RingBuffer::<i32, 4>::new();
+        rb.push_back_multiple(&[0, 0, 1, 2]);
+        rb.pop_front();
+        rb.pop_front();
+        rb.push_back_multiple(&[3, 4]); // wrapped: tail > head
+
+        for value in rb.iter_mut() {
+            *value *= 2;
+        }
+        assert_eq!(rb.iter().collect::<Vec<_>>(), [2, 4, 6, 8]);
+
+        *rb.get_mut(3).unwrap() += 1;
+        for value in &mut rb {
+            *value += 10;
+        }
+        assert!(rb.eq_slice(&[12, 14, 16, 19]));
+        assert_eq!(rb.get_mut(4), None);
+        assert_eq!(rb.iter_mut().len(), 4);
+    }
+
+    #[test]
+    fn test_zero_capacity_lookups_return_none() {
+        let mut rb = RingBuffer::<i32, 0>::new();
+        assert_eq!(rb.get(0), None);
+        assert_eq!(rb.get_mut(0), None);
+    }
 }
@@ -0,0 +1,449 @@
+use crate::cards::{Card, PotOrder, ValueTable};
+use crate::game::{GameConfig, GameError, GameResult, WarVariant};
+
+/// A `WarGame` frozen between rounds, when the pot is always empty
+/// Only the rules are kept from its config; how a resumed game is shown
+/// (prompts, animation, output format) is up to whoever resumes it
+#[derive(Debug, Clone)]
+pub struct SavedGame {
+    /// Rounds played so far
+    pub round: usize,
+    /// Each seated player's cards, player 1 first, each hand bottom first
+    pub hands: Vec<Vec<Card>>,
+    /// Chips per seated player, for `target_score` games
+    pub scores: Vec<u64>,
+    /// The rule settings the game was played under
+    pub config: GameConfig,
+}
+
+impl SavedGame {
+    /// Render as a JSON object, one field per line
+    pub fn to_json(&self) -> String {
+        let hands: Vec<String> = self.hands.iter().map(|hand| json_cards(hand)).collect();
+        let scores: Vec<String> = self.scores.iter().map(u64::to_string).collect();
+        let mut fields = vec![
+            ("round", self.round.to_string()),
+            ("hands", format!("[{}]", hands.join(","))),
+            ("scores", format!("[{}]", scores.join(","))),
+        ];
+        fields.extend(rules_json(&self.config));
+        json_object(&fields)
+    }
+
+    /// Parse the output of `to_json`
+    pub fn from_json(text: &str) -> GameResult<SavedGame> {
+        let json = Json::parse(text)?;
+
+        let hands = json
+            .field("hands")?
+            .as_array()?
+            .iter()
+            .map(|hand| hand.as_array()?.iter().map(Json::as_card).collect())
+            .collect::<GameResult<Vec<Vec<Card>>>>()?;
+        let scores = json
+            .field("scores")?
+            .as_array()?
+            .iter()
+            .map(Json::as_u64)
+            .collect::<GameResult<Vec<u64>>>()?;
+        if scores.len() != hands.len() {
+            return Err(invalid("one score is needed per hand"));
+        }
+
+        Ok(SavedGame {
+            round: small(json.field("round")?.as_u64()?)?,
+            config: rules_from_json(&json, hands.len())?,
+            hands,
+            scores,
+        })
+    }
+}
+
+/// The rule settings of `config` as JSON object fields; presentation
+/// settings are left out
+pub(crate) fn rules_json(config: &GameConfig) -> Vec<(&'static str, String)> {
+    let values: Vec<String> = config.values.0.iter().map(u8::to_string).collect();
+    let pot_order = match config.pot_order {
+        PotOrder::AsPlayed => "as_played",
+        PotOrder::WinnerFirst => "winner_first",
+        PotOrder::LoserFirst => "loser_first",
+    };
+    let war_variant = match config.war_variant {
+        WarVariant::BurnThree => "burn_three",
+        WarVariant::OneDownOneUp => "one_down_one_up",
+    };
+
+    vec![
+        ("test_mode", config.test_mode.to_string()),
+        ("war_tolerance", config.war_tolerance.to_string()),
+        ("target_score", json_option(config.target_score)),
+        ("values", format!("[{}]", values.join(","))),
+        ("pot_order", format!("\"{}\"", pot_order)),
+        ("show_burns", config.show_burns.to_string()),
+        ("deal_offset", config.deal_offset.to_string()),
+        ("max_rounds", json_option(config.max_rounds)),
+        ("war_variant", format!("\"{}\"", war_variant)),
+        ("detect_cycles", config.detect_cycles.to_string()),
+        ("shuffle_winnings", config.shuffle_winnings.to_string()),
+    ]
+}
+
+/// Read back the fields written by `rules_json` for a game of `players`
+pub(crate) fn rules_from_json(json: &Json, players: usize) -> GameResult<GameConfig> {
+    let values = json.field("values")?.as_array()?;
+    let mut table = ValueTable::identity();
+    if values.len() != table.0.len() {
+        return Err(invalid(format!(
+            "\"values\" needs {} entries",
+            table.0.len()
+        )));
+    }
+    for (slot, value) in table.0.iter_mut().zip(values) {
+        *slot = small(value.as_u64()?)?;
+    }
+
+    Ok(GameConfig {
+        test_mode: json.field("test_mode")?.as_bool()?,
+        war_tolerance: small(json.field("war_tolerance")?.as_u64()?)?,
+        target_score: json
+            .field("target_score")?
+            .as_option(Json::as_u64)?
+            .map(small)
+            .transpose()?,
+        values: table,
+        pot_order: match json.field("pot_order")?.as_str()? {
+            "as_played" => PotOrder::AsPlayed,
+            "winner_first" => PotOrder::WinnerFirst,
+            "loser_first" => PotOrder::LoserFirst,
+            other => return Err(invalid(format!("unknown pot order \"{}\"", other))),
+        },
+        show_burns: json.field("show_burns")?.as_bool()?,
+        deal_offset: small(json.field("deal_offset")?.as_u64()?)?,
+        max_rounds: json
+            .field("max_rounds")?
+            .as_option(Json::as_u64)?
+            .map(small)
+            .transpose()?,
+        war_variant: match json.field("war_variant")?.as_str()? {
+            "burn_three" => WarVariant::BurnThree,
+            "one_down_one_up" => WarVariant::OneDownOneUp,
+            other => return Err(invalid(format!("unknown war variant \"{}\"", other))),
+        },
+        players,
+        detect_cycles: json.field("detect_cycles")?.as_bool()?,
+        shuffle_winnings: json.field("shuffle_winnings")?.as_bool()?,
+        ..GameConfig::default()
+    })
+}
+
+pub(crate) fn invalid(message: impl Into<String>) -> GameError {
+    GameError::InvalidSave(message.into())
+}
+
+/// A JSON object with one field per line
+pub(crate) fn json_object(fields: &[(&str, String)]) -> String {
+    let fields: Vec<String> = fields
+        .iter()
+        .map(|(key, value)| format!("  \"{}\": {}", key, value))
+        .collect();
+    format!("{{\n{}\n}}\n", fields.join(",\n"))
+}
+
+/// Narrow a JSON number to the field's type
+pub(crate) fn small<T: TryFrom<u64>>(value: u64) -> GameResult<T> {
+    T::try_from(value).map_err(|_| invalid(format!("{} is out of range", value)))
+}
+
+/// `["AH","10S"]`
+fn json_cards(cards: &[Card]) -> String {
+    let codes: Vec<String> = cards
+        .iter()
+        .map(|card| format!("\"{}\"", card.code()))
+        .collect();
+    format!("[{}]", codes.join(","))
+}
+
+fn json_option<T: ToString>(value: Option<T>) -> String {
+    value.map_or_else(|| "null".to_string(), |value| value.to_string())
+}
+
+/// Just enough JSON to read our own files back: no fractions, negative
+/// numbers or string escapes, none of which this crate writes
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Json {
+    Null,
+    Bool(bool),
+    Number(u64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub(crate) fn parse(text: &str) -> GameResult<Json> {
+        Parser::parse(text)
+    }
+
+    pub(crate) fn field(&self, key: &str) -> GameResult<&Json> {
+        match self {
+            Json::Object(members) => members
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value)
+                .ok_or_else(|| invalid(format!("missing \"{}\"", key))),
+            _ => Err(invalid("expected an object")),
+        }
+    }
+
+    pub(crate) fn as_bool(&self) -> GameResult<bool> {
+        match *self {
+            Json::Bool(value) => Ok(value),
+            _ => Err(invalid("expected true or false")),
+        }
+    }
+
+    pub(crate) fn as_u64(&self) -> GameResult<u64> {
+        match *self {
+            Json::Number(value) => Ok(value),
+            _ => Err(invalid("expected a number")),
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> GameResult<&str> {
+        match self {
+            Json::String(value) => Ok(value),
+            _ => Err(invalid("expected a string")),
+        }
+    }
+
+    pub(crate) fn as_array(&self) -> GameResult<&[Json]> {
+        match self {
+            Json::Array(items) => Ok(items),
+            _ => Err(invalid("expected an array")),
+        }
+    }
+
+    pub(crate) fn as_card(&self) -> GameResult<Card> {
+        Ok(self.as_str()?.parse()?)
+    }
+
+    /// `None` for `null`, otherwise the value read by `read`
+    pub(crate) fn as_option<T>(
+        &self,
+        read: impl Fn(&Json) -> GameResult<T>,
+    ) -> GameResult<Option<T>> {
+        match self {
+            Json::Null => Ok(None),
+            value => read(value).map(Some),
+        }
+    }
+}
+
+struct Parser<'a> {
+    text: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn parse(text: &str) -> GameResult<Json> {
+        let mut parser = Parser {
+            text: text.as_bytes(),
+            pos: 0,
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.pos != parser.text.len() {
+            return Err(invalid(format!("unexpected text at byte {}", parser.pos)));
+        }
+        Ok(value)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|byte| byte.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> GameResult<()> {
+        self.skip_whitespace();
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(invalid(format!(
+                "expected '{}' at byte {}",
+                byte as char, self.pos
+            )))
+        }
+    }
+
+    fn value(&mut self) -> GameResult<Json> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'n') => self.keyword("null", Json::Null),
+            Some(b't') => self.keyword("true", Json::Bool(true)),
+            Some(b'f') => self.keyword("false", Json::Bool(false)),
+            Some(b'"') => self.string().map(Json::String),
+            Some(b'[') => self.list(b'[', b']', Self::value).map(Json::Array),
+            Some(b'{') => self.list(b'{', b'}', Self::member).map(Json::Object),
+            Some(b'0'..=b'9') => self.number(),
+            _ => Err(invalid(format!("unexpected input at byte {}", self.pos))),
+        }
+    }
+
+    fn keyword(&mut self, word: &str, value: Json) -> GameResult<Json> {
+        if self.text[self.pos..].starts_with(word.as_bytes()) {
+            self.pos += word.len();
+            Ok(value)
+        } else {
+            Err(invalid(format!("unexpected input at byte {}", self.pos)))
+        }
+    }
+
+    fn number(&mut self) -> GameResult<Json> {
+        let start = self.pos;
+        while self.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        let digits = std::str::from_utf8(&self.text[start..self.pos]).unwrap_or_default();
+        digits
+            .parse()
+            .map(Json::Number)
+            .map_err(|_| invalid(format!("{} is out of range", digits)))
+    }
+
+    fn string(&mut self) -> GameResult<String> {
+        self.expect(b'"')?;
+        let start = self.pos;
+        loop {
+            match self.peek() {
+                Some(b'"') => break,
+                Some(b'\\') => return Err(invalid("string escapes are not supported")),
+                Some(_) => self.pos += 1,
+                None => return Err(invalid("unterminated string")),
+            }
+        }
+        let text = std::str::from_utf8(&self.text[start..self.pos])
+            .map_err(|_| invalid("strings must be UTF-8"))?;
+        self.pos += 1;
+        Ok(text.to_string())
+    }
+
+    fn member(&mut self) -> GameResult<(String, Json)> {
+        self.skip_whitespace();
+        let key = self.string()?;
+        self.expect(b':')?;
+        Ok((key, self.value()?))
+    }
+
+    /// Comma-separated `item`s between `open` and `close`
+    fn list<T>(
+        &mut self,
+        open: u8,
+        close: u8,
+        item: fn(&mut Self) -> GameResult<T>,
+    ) -> GameResult<Vec<T>> {
+        self.expect(open)?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(close) {
+            self.pos += 1;
+            return Ok(items);
+        }
+        loop {
+            items.push(item(self)?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(byte) if byte == close => {
+                    self.pos += 1;
+                    return Ok(items);
+                }
+                _ => {
+                    return Err(invalid(format!(
+                        "expected ',' or '{}' at byte {}",
+                        close as char, self.pos
+                    )));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::{Rank, Suit};
+
+    fn saved() -> SavedGame {
+        SavedGame {
+            round: 41,
+            hands: vec![
+                vec![
+                    Card::new(Suit::Hearts, Rank::Ace),
+                    Card::new(Suit::Spades, Rank::Ten),
+                ],
+                vec![Card::new(Suit::Clubs, Rank::Two)],
+                Vec::new(),
+            ],
+            scores: vec![12, 0, 7],
+            config: GameConfig {
+                test_mode: true,
+                war_tolerance: 1,
+                target_score: Some(500),
+                values: ValueTable::ace_low(),
+                pot_order: PotOrder::LoserFirst,
+                war_variant: WarVariant::OneDownOneUp,
+                players: 3,
+                shuffle_winnings: true,
+                ..GameConfig::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_saved_game_round_trips_through_json() {
+        let saved = saved();
+        let json = saved.to_json();
+        assert!(json.contains("\"hands\": [[\"AH\",\"10S\"],[\"2C\"],[]]"));
+        assert!(json.contains("\"max_rounds\": null"));
+
+        let loaded = SavedGame::from_json(&json).unwrap();
+        assert_eq!(loaded.round, saved.round);
+        assert_eq!(loaded.hands, saved.hands);
+        assert_eq!(loaded.scores, saved.scores);
+        assert_eq!(loaded.config.values, ValueTable::ace_low());
+        assert_eq!(loaded.config.pot_order, PotOrder::LoserFirst);
+        assert_eq!(loaded.config.war_variant, WarVariant::OneDownOneUp);
+        assert_eq!(loaded.config.target_score, Some(500));
+        assert_eq!(loaded.config.players, 3);
+        assert!(loaded.config.test_mode && loaded.config.shuffle_winnings);
+    }
+
+    #[test]
+    fn test_damaged_save_is_rejected() {
+        let json = saved().to_json();
+        for damaged in [
+            String::new(),
+            json.replace("\"round\"", "\"rounds\""),
+            json.replace("\"2C\"", "\"1C\""),
+            json.replace("[12,0,7]", "[12,0]"),
+            json.replace("one_down_one_up", "two_down"),
+            json.replace("\"war_tolerance\": 1", "\"war_tolerance\": 300"),
+            format!("{} {{}}", json),
+        ] {
+            assert!(
+                matches!(
+                    SavedGame::from_json(&damaged),
+                    Err(GameError::InvalidSave(_) | GameError::InvalidCard(_))
+                ),
+                "accepted {:?}",
+                damaged
+            );
+        }
+    }
+}
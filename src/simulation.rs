@@ -0,0 +1,562 @@
+use crate::cards::{Card, Deck, PlayerHand};
+use crate::engine::GameEngine;
+use crate::game::{EndReason, GameConfig, MAX_PLAYERS};
+use crate::observer::GameObserver;
+use crate::stats::{GameStats, count_leader};
+use std::fmt;
+
+/// What happened in a single round of a `Simulation`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundSummary {
+    pub round: usize,
+    /// The player who took the pot
+    pub winner: usize,
+    /// Number of cards the winner collected
+    pub pot_size: usize,
+    /// Whether the round went to war
+    pub war: bool,
+}
+
+impl RoundSummary {
+    /// Render as a single-line JSON object
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"round\":{},\"winner\":{},\"pot_size\":{},\"war\":{}}}",
+            self.round, self.winner, self.pot_size, self.war
+        )
+    }
+}
+
+/// Final state of a simulated game
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameSummary {
+    pub rounds: usize,
+    /// The player who won, or who held more cards when the round limit hit
+    /// (`None` if the counts were tied, or the game looped; see
+    /// `GameConfig::detect_cycles`)
+    pub winner: Option<usize>,
+    pub player1_cards: usize,
+    pub player2_cards: usize,
+    pub wars: usize,
+    /// True if the round limit ended the game before a player ran out of cards
+    /// A war is part of a single round, so it is always resolved (and its pot
+    /// counted in the card totals) before the limit applies
+    pub truncated: bool,
+}
+
+impl GameSummary {
+    /// Render as a single-line JSON object (`winner` is null for a tie)
+    pub fn to_json(&self) -> String {
+        let winner = match self.winner {
+            Some(player) => player.to_string(),
+            None => "null".to_string(),
+        };
+        format!(
+            "{{\"rounds\":{},\"winner\":{},\"player1_cards\":{},\"player2_cards\":{},\"wars\":{},\"truncated\":{}}}",
+            self.rounds, winner, self.player1_cards, self.player2_cards, self.wars, self.truncated
+        )
+    }
+}
+
+impl fmt::Display for GameSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.truncated {
+            write!(f, "Round limit reached after {} rounds: ", self.rounds)?;
+            match self.winner {
+                Some(player) => write!(f, "Player {} leads", player)?,
+                None => write!(f, "tied")?,
+            }
+            write!(
+                f,
+                " {} to {} ({} wars)",
+                self.player1_cards, self.player2_cards, self.wars
+            )
+        } else if let Some(winner) = self.winner {
+            write!(
+                f,
+                "Player {} wins after {} rounds ({} wars)",
+                winner, self.rounds, self.wars
+            )
+        } else {
+            write!(
+                f,
+                "Draw after {} rounds: the game loops forever ({} wars)",
+                self.rounds, self.wars
+            )
+        }
+    }
+}
+
+/// Notes what the round `Simulation::step` plays did
+#[derive(Default)]
+struct RoundRecorder {
+    /// The round's winner and the size of the pot they took
+    won: Option<(usize, usize)>,
+    war: bool,
+}
+
+impl GameObserver for RoundRecorder {
+    fn on_war(&mut self, _players: &[usize], _pot_size: usize) {
+        self.war = true;
+    }
+
+    fn on_round_won(&mut self, _round: usize, winner: usize, pot_size: usize) {
+        self.won = Some((winner, pot_size));
+    }
+}
+
+/// A headless two-player game of War with no I/O, suitable for embedding
+/// (including wasm32)
+/// A thin layer over `GameEngine`, so every rule in its `GameConfig` applies
+/// as it does to a `WarGame`, round limit included; `run` can stop sooner
+pub struct Simulation {
+    engine: GameEngine,
+}
+
+impl Simulation {
+    /// Create a simulation from a seeded shuffle with the default rules
+    pub fn new(seed: u64) -> Self {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(seed);
+        Simulation::from_deck(deck, GameConfig::default())
+    }
+
+    /// Deal a prepared deck; presentation options in `config` are ignored
+    /// Panics unless `config.players` is 2
+    pub fn from_deck(deck: Deck, config: GameConfig) -> Self {
+        Simulation {
+            engine: GameEngine::from_deck(deck, Self::seat_pair(config)),
+        }
+    }
+
+    /// Start from hands that have already been dealt
+    /// Panics unless `config.players` is 2
+    pub fn from_hands(
+        player1_cards: PlayerHand,
+        player2_cards: PlayerHand,
+        config: GameConfig,
+    ) -> Self {
+        let mut hands: [PlayerHand; MAX_PLAYERS] = Default::default();
+        hands[0] = player1_cards;
+        hands[1] = player2_cards;
+        Simulation {
+            engine: GameEngine::from_hands(hands, 0, GameStats::default(), Self::seat_pair(config)),
+        }
+    }
+
+    fn seat_pair(config: GameConfig) -> GameConfig {
+        assert_eq!(
+            config.players, 2,
+            "a Simulation seats two players, got {}",
+            config.players
+        );
+        config
+    }
+
+    /// Number of rounds played so far
+    pub fn round(&self) -> usize {
+        self.engine.round()
+    }
+
+    /// Returns true once the game has ended, with a winner or in a draw
+    pub fn is_finished(&self) -> bool {
+        self.engine.is_finished()
+    }
+
+    /// The winner once the game is over (`None` while it is in progress, or
+    /// if it ended in a draw)
+    pub fn winner(&self) -> Option<usize> {
+        self.engine.winner()
+    }
+
+    /// Cards held by each player
+    pub fn card_counts(&self) -> (usize, usize) {
+        (self.engine.hand(1).len(), self.engine.hand(2).len())
+    }
+
+    /// The pair of cards the next round will open with
+    pub fn next_cards(&self) -> Option<(Card, Card)> {
+        Some((
+            self.engine.hand(1).peek_top()?,
+            self.engine.hand(2).peek_top()?,
+        ))
+    }
+
+    /// Play one round, or return None if the game is already over
+    /// Panics if the pot overflows, which only hands holding more than a
+    /// deck's worth of cards can cause
+    pub fn step(&mut self) -> Option<RoundSummary> {
+        let mut recorder = RoundRecorder::default();
+        if let Err(error) = self.engine.step_observed(&mut recorder) {
+            panic!("round {} failed: {}", self.engine.round(), error);
+        }
+        let (winner, pot_size) = recorder.won?;
+        Some(RoundSummary {
+            round: self.engine.round(),
+            winner,
+            pot_size,
+            war: recorder.war,
+        })
+    }
+
+    /// Play until the game ends or `max_rounds` rounds have been played
+    pub fn run(&mut self, max_rounds: usize) -> GameSummary {
+        while self.round() < max_rounds && self.step().is_some() {}
+        self.summary()
+    }
+
+    /// Snapshot of the game so far
+    pub fn summary(&self) -> GameSummary {
+        let (player1_cards, player2_cards) = self.card_counts();
+        let (winner, truncated) = match self.engine.outcome() {
+            Some(outcome) => (
+                outcome.winner,
+                outcome.reason == EndReason::TruncatedByLimit,
+            ),
+            None => (count_leader(&[player1_cards, player2_cards]), true),
+        };
+
+        GameSummary {
+            rounds: self.round(),
+            winner,
+            player1_cards,
+            player2_cards,
+            wars: self.engine.stats().wars as usize,
+            truncated,
+        }
+    }
+}
+
+impl fmt::Debug for Simulation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Simulation")
+            .field("round", &self.round())
+            .field("card_counts", &self.card_counts())
+            .field("outcome", &self.engine.outcome())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Simulate a full seeded game with the default rules
+pub fn simulate(seed: u64, max_rounds: usize) -> GameSummary {
+    simulate_with(seed, GameConfig::default(), max_rounds)
+}
+
+/// Simulate a full seeded game under the rules in `config`
+pub fn simulate_with(seed: u64, config: GameConfig, max_rounds: usize) -> GameSummary {
+    let mut deck = Deck::new();
+    deck.shuffle_with_seed(seed);
+    Simulation::from_deck(deck, config).run(max_rounds)
+}
+
+/// A seeded game and the same deal with the players' hands swapped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MirrorCheck {
+    pub original: GameSummary,
+    pub mirrored: GameSummary,
+}
+
+impl MirrorCheck {
+    /// True if the mirrored game is the original with the players relabelled:
+    /// same length and wars, opposite winner and card counts
+    pub fn is_symmetric(&self) -> bool {
+        let (a, b) = (self.original, self.mirrored);
+        a.rounds == b.rounds
+            && a.wars == b.wars
+            && a.truncated == b.truncated
+            && a.winner.map(|player| 3 - player) == b.winner
+            && (a.player1_cards, a.player2_cards) == (b.player2_cards, b.player1_cards)
+    }
+}
+
+/// Play `seed` under `config`, then again with the hands swapped by flipping
+/// `config.deal_offset`, to expose any rule that favours one seat
+pub fn mirror_check(seed: u64, config: GameConfig, max_rounds: usize) -> MirrorCheck {
+    let mirrored = GameConfig {
+        deal_offset: config.deal_offset + 1,
+        ..config
+    };
+    MirrorCheck {
+        original: simulate_with(seed, config, max_rounds),
+        mirrored: simulate_with(seed, mirrored, max_rounds),
+    }
+}
+
+/// Two seeded games side by side, to show how much the deal decides
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeedComparison {
+    pub seeds: [u64; 2],
+    pub summaries: [GameSummary; 2],
+}
+
+/// Simulate both seeds under the rules in `config`
+pub fn compare_seeds(
+    seed_a: u64,
+    seed_b: u64,
+    config: GameConfig,
+    max_rounds: usize,
+) -> SeedComparison {
+    SeedComparison {
+        seeds: [seed_a, seed_b],
+        summaries: [
+            simulate_with(seed_a, config, max_rounds),
+            simulate_with(seed_b, config, max_rounds),
+        ],
+    }
+}
+
+impl fmt::Display for SeedComparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let winner = |summary: &GameSummary| match (summary.winner, summary.truncated) {
+            (Some(player), false) => format!("Player {}", player),
+            (Some(player), true) => format!("Player {} (on cards)", player),
+            (None, _) => "tie".to_string(),
+        };
+        let [a, b] = &self.summaries;
+
+        writeln!(
+            f,
+            "{:<8} {:>22} {:>22}",
+            "",
+            format!("seed {}", self.seeds[0]),
+            format!("seed {}", self.seeds[1])
+        )?;
+        writeln!(f, "{:<8} {:>22} {:>22}", "Rounds", a.rounds, b.rounds)?;
+        writeln!(f, "{:<8} {:>22} {:>22}", "Winner", winner(a), winner(b))?;
+        write!(f, "{:<8} {:>22} {:>22}", "Wars", a.wars, b.wars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::{PotOrder, Rank, Suit};
+    use crate::engine::StepResult;
+    use crate::game::DEFAULT_MAX_ROUNDS;
+
+    #[test]
+    fn test_simulation_is_deterministic_and_conserves_cards() {
+        let summary = simulate(42, DEFAULT_MAX_ROUNDS);
+
+        assert_eq!(summary, simulate(42, DEFAULT_MAX_ROUNDS));
+        assert_eq!(summary.player1_cards + summary.player2_cards, 52);
+        assert!(summary.winner.is_some() || summary.truncated);
+    }
+
+    #[test]
+    fn test_war_over_a_joker_deck_keeps_every_card() {
+        // Every pair ties, so the first round is one war that pulls in the
+        // whole 54-card deck
+        let deal = |suits: [Suit; 2]| {
+            let mut hand = PlayerHand::new();
+            for rank in Rank::all() {
+                for suit in suits {
+                    hand.add_card(Card::new(suit, rank));
+                }
+            }
+            hand.add_card(Card::new(suits[0], Rank::Joker));
+            hand
+        };
+        let mut simulation = Simulation::from_hands(
+            deal([Suit::Hearts, Suit::Clubs]),
+            deal([Suit::Spades, Suit::Diamonds]),
+            GameConfig::default(),
+        );
+
+        let round = simulation.step().unwrap();
+        assert!(round.war);
+        let (len1, len2) = simulation.card_counts();
+        assert_eq!(len1 + len2, 54);
+    }
+
+    #[test]
+    fn test_simulation_plays_by_the_engine_rules() {
+        let deal = |seed| {
+            let mut deck = Deck::new();
+            deck.shuffle_with_seed(seed);
+            deck
+        };
+        let configs = [
+            GameConfig::default(),
+            GameConfig {
+                target_score: Some(300),
+                ..GameConfig::default()
+            },
+            GameConfig {
+                detect_cycles: true,
+                ..GameConfig::default()
+            },
+            GameConfig {
+                max_rounds: Some(100),
+                war_tolerance: 1,
+                ..GameConfig::default()
+            },
+        ];
+        for config in configs {
+            for seed in 0..20 {
+                let summary = Simulation::from_deck(deal(seed), config).run(DEFAULT_MAX_ROUNDS);
+                let mut engine = GameEngine::from_deck(deal(seed), config);
+                let outcome = loop {
+                    if let StepResult::Finished(outcome) = engine.step().unwrap() {
+                        break outcome;
+                    }
+                };
+                assert_eq!(summary.rounds, outcome.rounds, "seed {}", seed);
+                assert_eq!(summary.winner, outcome.winner, "seed {}", seed);
+                assert_eq!(
+                    [summary.player1_cards, summary.player2_cards],
+                    outcome.final_counts[..]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_looping_game_is_a_draw() {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(23);
+        let config = GameConfig {
+            detect_cycles: true,
+            ..GameConfig::default()
+        };
+        let summary = Simulation::from_deck(deck, config).run(DEFAULT_MAX_ROUNDS);
+
+        assert_eq!((summary.winner, summary.truncated), (None, false));
+        assert!(summary.to_string().starts_with("Draw after 260 rounds"));
+    }
+
+    #[test]
+    #[should_panic(expected = "seats two players")]
+    fn test_simulation_rejects_other_table_sizes() {
+        let config = GameConfig {
+            players: 3,
+            ..GameConfig::default()
+        };
+        Simulation::from_deck(Deck::new(), config);
+    }
+
+    #[test]
+    fn test_round_summary_json() {
+        let event = RoundSummary {
+            round: 3,
+            winner: 2,
+            pot_size: 10,
+            war: true,
+        };
+        assert_eq!(
+            event.to_json(),
+            r#"{"round":3,"winner":2,"pot_size":10,"war":true}"#
+        );
+    }
+
+    #[test]
+    fn test_step_reports_each_round() {
+        let mut simulation = Simulation::new(7);
+
+        let first = simulation.step().unwrap();
+        assert_eq!(first.round, 1);
+        assert!(first.pot_size >= 2);
+        assert_eq!(simulation.round(), 1);
+
+        let summary = simulation.run(5);
+        assert_eq!(summary.rounds, 5);
+        assert!(summary.truncated);
+    }
+
+    #[test]
+    fn test_simulation_does_not_use_std_io() {
+        // Split the needles so they don't match this test's own source
+        // A Simulation is a thin layer over a GameEngine, so check both
+        for (file, source) in [
+            ("simulation.rs", include_str!("simulation.rs")),
+            ("engine.rs", include_str!("engine.rs")),
+        ] {
+            check_io_free(file, source);
+        }
+    }
+
+    fn check_io_free(file: &str, source: &str) {
+        for needle in [
+            concat!("print", "!"),
+            concat!("print", "ln!"),
+            concat!("eprint", "ln!"),
+            concat!("std", "::io"),
+            concat!("std", "in()"),
+            concat!("std", "out()"),
+            concat!("thread", "::sleep"),
+        ] {
+            assert!(
+                !source.contains(needle),
+                "{} must stay I/O free but uses `{}`",
+                file,
+                needle
+            );
+        }
+    }
+
+    #[test]
+    fn test_compare_seeds_shows_both_outcomes() {
+        let comparison = compare_seeds(1, 4, GameConfig::default(), DEFAULT_MAX_ROUNDS);
+        assert_eq!(comparison.summaries[0], simulate(1, DEFAULT_MAX_ROUNDS));
+
+        let table = comparison.to_string();
+        assert!(table.contains("seed 1") && table.contains("seed 4"));
+        let winners = table
+            .lines()
+            .find(|line| line.starts_with("Winner"))
+            .unwrap();
+        assert!(winners.contains("Player 1") && winners.contains("Player 2"));
+    }
+
+    #[test]
+    fn test_swapping_hands_swaps_the_outcome() {
+        // Pot order must be by role, not by seat, for the mirror to be exact
+        let config = GameConfig {
+            pot_order: PotOrder::WinnerFirst,
+            ..GameConfig::default()
+        };
+        for seed in 0..32 {
+            let check = mirror_check(seed, config, DEFAULT_MAX_ROUNDS);
+            assert!(check.is_symmetric(), "seed {}: {:?}", seed, check);
+        }
+
+        // The default, `PotOrder::AsPlayed`, returns the pot in seat order,
+        // so swapping the hands changes how every one of these games plays out
+        let asymmetric = (0..32)
+            .filter(|&seed| {
+                !mirror_check(seed, GameConfig::default(), DEFAULT_MAX_ROUNDS).is_symmetric()
+            })
+            .count();
+        assert_eq!(
+            asymmetric, 32,
+            "AsPlayed should break the mirror on every seed"
+        );
+    }
+
+    #[test]
+    fn test_shuffled_winnings_end_more_games() {
+        let capped = |shuffle_winnings| {
+            let config = GameConfig {
+                shuffle_winnings,
+                ..GameConfig::default()
+            };
+            (0..100)
+                .filter(|&seed| {
+                    let mut deck = Deck::new();
+                    deck.shuffle_with_seed(seed);
+                    Simulation::from_deck(deck, config)
+                        .run(DEFAULT_MAX_ROUNDS)
+                        .truncated
+                })
+                .count()
+        };
+
+        let (fixed, shuffled) = (capped(false), capped(true));
+        assert!(
+            shuffled < fixed,
+            "{} shuffled vs {} fixed games hit the cap",
+            shuffled,
+            fixed
+        );
+    }
+}
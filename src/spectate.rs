@@ -0,0 +1,142 @@
+use crate::animation::Clock;
+use crate::game::GameResult;
+use crate::simulation::{GameSummary, RoundSummary, Simulation};
+use std::io::{self, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+/// Pause between broadcast rounds so spectators can follow along
+pub const SPECTATE_ROUND_DELAY: Duration = Duration::from_millis(250);
+
+/// Read-only TCP spectators, each sent one JSON line per round
+/// Clients that disconnect are dropped on the next broadcast; the game carries on
+#[derive(Debug)]
+pub struct Spectators {
+    listener: TcpListener,
+    clients: Vec<TcpStream>,
+}
+
+impl Spectators {
+    /// Listen for spectators on `addr` (port 0 picks a free port)
+    pub fn bind<A: ToSocketAddrs>(addr: A) -> GameResult<Self> {
+        let listener = TcpListener::bind(addr)?;
+        // Accepting must never stall the game
+        listener.set_nonblocking(true)?;
+        Ok(Spectators {
+            listener,
+            clients: Vec::new(),
+        })
+    }
+
+    pub fn local_addr(&self) -> GameResult<SocketAddr> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    /// Number of spectators still connected
+    pub fn len(&self) -> usize {
+        self.clients.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+
+    /// Pick up anyone who connected since the last call
+    fn accept_pending(&mut self) {
+        loop {
+            match self.listener.accept() {
+                Ok((stream, _)) => {
+                    // Some platforms hand out sockets that inherit non-blocking mode
+                    if stream.set_nonblocking(false).is_ok() {
+                        self.clients.push(stream);
+                    }
+                }
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                // A failed handshake only affects that client
+                Err(_) => break,
+            }
+        }
+    }
+
+    /// Send `event` to every spectator, dropping those whose connection failed
+    pub fn broadcast(&mut self, event: &RoundSummary) {
+        self.accept_pending();
+
+        let mut line = event.to_json();
+        line.push('\n');
+        self.clients
+            .retain_mut(|client| client.write_all(line.as_bytes()).is_ok());
+    }
+}
+
+/// Play `simulation` out, broadcasting each round and pausing `delay` between them
+pub fn broadcast_game<C: Clock>(
+    simulation: &mut Simulation,
+    spectators: &mut Spectators,
+    clock: &mut C,
+    delay: Duration,
+    max_rounds: usize,
+) -> GameSummary {
+    while simulation.round() < max_rounds {
+        let Some(event) = simulation.step() else {
+            break;
+        };
+        spectators.broadcast(&event);
+        clock.sleep(delay);
+    }
+    simulation.summary()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+
+    struct NoDelay;
+
+    impl Clock for NoDelay {
+        fn sleep(&mut self, _duration: Duration) {}
+    }
+
+    #[test]
+    fn test_spectator_receives_round_events() {
+        let mut spectators = Spectators::bind("127.0.0.1:0").unwrap();
+        let client = TcpStream::connect(spectators.local_addr().unwrap()).unwrap();
+
+        let mut simulation = Simulation::new(42);
+        broadcast_game(
+            &mut simulation,
+            &mut spectators,
+            &mut NoDelay,
+            Duration::ZERO,
+            3,
+        );
+        assert_eq!(spectators.len(), 1);
+
+        let mut first = String::new();
+        BufReader::new(client).read_line(&mut first).unwrap();
+        assert!(first.starts_with("{\"round\":1,"), "got {}", first);
+    }
+
+    #[test]
+    fn test_disconnected_spectator_is_dropped() {
+        let mut spectators = Spectators::bind("127.0.0.1:0").unwrap();
+        let client = TcpStream::connect(spectators.local_addr().unwrap()).unwrap();
+
+        let mut simulation = Simulation::new(7);
+        spectators.broadcast(&simulation.step().unwrap());
+        assert_eq!(spectators.len(), 1);
+        drop(client);
+
+        // The first write after a hang-up may still be buffered, so keep playing
+        let summary = broadcast_game(
+            &mut simulation,
+            &mut spectators,
+            &mut NoDelay,
+            Duration::ZERO,
+            50,
+        );
+        assert_eq!(summary.rounds, 50);
+        assert!(spectators.is_empty());
+    }
+}
@@ -0,0 +1,259 @@
+use crate::game::MAX_PLAYERS;
+use std::fmt;
+
+/// Consecutive round wins at which a streak is called out
+pub const STREAK_THRESHOLD: usize = 5;
+
+/// Running totals collected while a game is played
+/// Accumulators are `u64` and saturate at `u64::MAX` instead of wrapping, so an
+/// absurdly long simulation reports a pinned maximum rather than a small, wrong total
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GameStats {
+    /// Chips per player: each pot won pays one chip per card in it
+    pub scores: [u64; MAX_PLAYERS],
+    /// Rounds resolved (each one credited to its winner)
+    pub rounds: u64,
+    /// Wars fought, counting a multi-level war once
+    pub wars: u64,
+    /// Most cards at stake in a single war, including the cards that started it
+    pub biggest_war: usize,
+    /// Most ties in a row within a single war: 1 for a war settled by its
+    /// first deciders, 2 if those tied again, and so on
+    pub longest_war: usize,
+    /// Most cards the leader has held over the runner-up after any round
+    pub max_lead: usize,
+    /// Cards that have changed hands, counted once per card per round it is lost
+    pub cards_exchanged: u64,
+    /// Most consecutive rounds won by one player
+    pub longest_streak: usize,
+    /// The player on the current streak and its length
+    streak: (usize, usize),
+    /// Player holding more cards after the latest round, if either
+    leader: Option<usize>,
+    /// Round in which `leader` last took the lead
+    lead_since: usize,
+    /// Each player's cards as the latest round began, to measure what changed hands
+    counts: [usize; MAX_PLAYERS],
+}
+
+impl GameStats {
+    /// Credit `player` (from 1) with a pot of `pot_size` cards
+    pub fn record_pot(&mut self, player: usize, pot_size: usize) {
+        let score = &mut self.scores[player - 1];
+        *score = score.saturating_add(pot_size as u64);
+    }
+
+    /// Chips held by `player` (from 1)
+    pub fn score(&self, player: usize) -> u64 {
+        self.scores[player - 1]
+    }
+
+    /// Count a round won by `player`, returning the streak length if it has
+    /// just reached `STREAK_THRESHOLD`
+    pub fn record_round_winner(&mut self, player: usize) -> Option<usize> {
+        self.rounds = self.rounds.saturating_add(1);
+        let (holder, length) = &mut self.streak;
+        if *holder == player {
+            *length += 1;
+        } else {
+            *holder = player;
+            *length = 1;
+        }
+        self.longest_streak = self.longest_streak.max(*length);
+        (*length == STREAK_THRESHOLD).then_some(*length)
+    }
+
+    /// The player on a winning streak and its length, if any rounds have been played
+    pub fn current_streak(&self) -> Option<(usize, usize)> {
+        (self.streak.1 > 0).then_some(self.streak)
+    }
+
+    /// Note a war that was decided with `pot_size` cards on the table after
+    /// `levels` ties in a row
+    pub fn record_war(&mut self, pot_size: usize, levels: usize) {
+        self.wars = self.wars.saturating_add(1);
+        self.biggest_war = self.biggest_war.max(pot_size);
+        self.longest_war = self.longest_war.max(levels);
+    }
+
+    /// Note each player's cards as a round begins, so `record_card_counts`
+    /// can tell how many changed hands
+    pub fn record_round_start(&mut self, counts: &[usize]) {
+        self.counts[..counts.len()].copy_from_slice(counts);
+    }
+
+    /// Fold another game's totals into these, for aggregating many games
+    /// Counts add up and records keep the larger value; the in-progress streak
+    /// and lead tracking stay as they were. `GameStats::default()` is the
+    /// identity, so it makes a natural starting total
+    pub fn merge(&mut self, other: &GameStats) {
+        for (score, other) in self.scores.iter_mut().zip(other.scores) {
+            *score = score.saturating_add(other);
+        }
+        self.rounds = self.rounds.saturating_add(other.rounds);
+        self.wars = self.wars.saturating_add(other.wars);
+        self.biggest_war = self.biggest_war.max(other.biggest_war);
+        self.longest_war = self.longest_war.max(other.longest_war);
+        self.max_lead = self.max_lead.max(other.max_lead);
+        self.cards_exchanged = self.cards_exchanged.saturating_add(other.cards_exchanged);
+        self.longest_streak = self.longest_streak.max(other.longest_streak);
+    }
+
+    /// Track the card-count lead after `round` has been resolved
+    /// `counts` holds each player's cards, player 1 first
+    pub fn record_card_counts(&mut self, round: usize, counts: &[usize]) {
+        let lost: usize = counts
+            .iter()
+            .zip(&self.counts)
+            .map(|(&count, &before)| before.saturating_sub(count))
+            .sum();
+        self.cards_exchanged = self.cards_exchanged.saturating_add(lost as u64);
+
+        let mut sorted = [0; MAX_PLAYERS];
+        let sorted = &mut sorted[..counts.len()];
+        sorted.copy_from_slice(counts);
+        sorted.sort_unstable_by(|a, b| b.cmp(a));
+        if let [most, runner_up, ..] = sorted[..] {
+            self.max_lead = self.max_lead.max(most - runner_up);
+        }
+
+        let leader = count_leader(counts);
+        if leader != self.leader {
+            self.leader = leader;
+            self.lead_since = round;
+        }
+    }
+
+    /// The round from which `winner` led on card count without ever
+    /// relinquishing it (a tie counts as losing the lead), or None if they
+    /// aren't currently ahead
+    pub fn decided_at_round(&self, winner: usize) -> Option<usize> {
+        (self.leader == Some(winner)).then_some(self.lead_since)
+    }
+}
+
+/// A two-column summary table, one statistic per line
+impl fmt::Display for GameStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<16} {:>8}", "Rounds", self.rounds)?;
+        writeln!(f, "{:<16} {:>8}", "Wars", self.wars)?;
+        writeln!(f, "{:<16} {:>8}", "Longest war", self.longest_war)?;
+        writeln!(f, "{:<16} {:>8}", "Biggest war", self.biggest_war)?;
+        writeln!(f, "{:<16} {:>8}", "Longest streak", self.longest_streak)?;
+        writeln!(f, "{:<16} {:>8}", "Largest lead", self.max_lead)?;
+        write!(f, "{:<16} {:>8}", "Cards exchanged", self.cards_exchanged)
+    }
+}
+
+/// The player (from 1) holding strictly more cards than anyone else, given
+/// each player's count with player 1 first
+pub fn count_leader(counts: &[usize]) -> Option<usize> {
+    let most = *counts.iter().max()?;
+    let mut leaders = counts
+        .iter()
+        .enumerate()
+        .filter(|&(_, &count)| count == most);
+    match (leaders.next(), leaders.next()) {
+        (Some((index, _)), None) => Some(index + 1),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scores_grow_past_u32_without_overflow() {
+        let mut stats = GameStats::default();
+        // Equivalent to ~82 million rounds of full 52-card pots
+        stats.scores[0] = u32::MAX as u64;
+        for _ in 0..1000 {
+            stats.record_pot(1, 52);
+        }
+
+        assert_eq!(stats.score(1), u32::MAX as u64 + 52_000);
+        assert_eq!(stats.score(2), 0);
+    }
+
+    #[test]
+    fn test_scores_saturate_instead_of_wrapping() {
+        let mut stats = GameStats::default();
+        stats.scores[1] = u64::MAX - 10;
+
+        stats.record_pot(2, 52);
+        stats.record_pot(2, 52);
+
+        assert_eq!(stats.score(2), u64::MAX);
+    }
+
+    #[test]
+    fn test_decided_at_round_tracks_the_last_lead_change() {
+        let mut stats = GameStats::default();
+        stats.record_card_counts(1, &[27, 25]);
+        stats.record_card_counts(2, &[26, 26]);
+        stats.record_card_counts(3, &[28, 24]);
+        stats.record_card_counts(4, &[30, 22]);
+
+        assert_eq!(stats.decided_at_round(1), Some(3));
+        assert_eq!(stats.decided_at_round(2), None);
+
+        stats.record_card_counts(5, &[20, 32]);
+        assert_eq!(stats.decided_at_round(2), Some(5));
+    }
+
+    #[test]
+    fn test_streaks_reset_on_a_loss_and_fire_once_at_threshold() {
+        let mut stats = GameStats::default();
+        let mut fired = Vec::new();
+        for (round, player) in [1, 1, 2, 2, 2, 2, 2, 2, 1].into_iter().enumerate() {
+            if let Some(length) = stats.record_round_winner(player) {
+                fired.push((round + 1, player, length));
+            }
+        }
+
+        assert_eq!(fired, vec![(7, 2, STREAK_THRESHOLD)]);
+        assert_eq!(stats.longest_streak, 6);
+        assert_eq!(stats.current_streak(), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_merge_sums_counts_and_keeps_records() {
+        let mut first = GameStats::default();
+        first.record_pot(1, 10);
+        first.record_round_winner(1);
+        first.record_war(10, 1);
+
+        let mut second = GameStats::default();
+        second.record_pot(2, 2);
+        second.record_round_winner(2);
+        second.record_pot(2, 18);
+        second.record_round_winner(2);
+        second.record_war(18, 2);
+
+        let mut total = GameStats::default();
+        total.merge(&first);
+        total.merge(&second);
+
+        assert_eq!(total.scores, [10, 20, 0, 0]);
+        assert_eq!(total.rounds, 3);
+        assert_eq!(total.wars, 2);
+        assert_eq!(total.biggest_war, 18);
+        assert_eq!(total.longest_war, 2);
+        assert_eq!(total.longest_streak, 2);
+        assert_eq!(total.current_streak(), None);
+    }
+
+    #[test]
+    fn test_card_counts_track_lead_and_cards_exchanged() {
+        let mut stats = GameStats::default();
+        stats.record_round_start(&[18, 17, 17]);
+        stats.record_card_counts(1, &[20, 16, 16]);
+        stats.record_round_start(&[20, 16, 16]);
+        stats.record_card_counts(2, &[14, 22, 16]);
+
+        // Players 2 and 3 lose one card each, then player 1 loses six
+        assert_eq!(stats.cards_exchanged, 8);
+        assert_eq!(stats.max_lead, 6);
+    }
+}
@@ -0,0 +1,175 @@
+use crate::game::{GameConfig, GameError, GameResult};
+use crate::simulation::{GameSummary, simulate_with};
+use std::fmt;
+
+/// A bracket participant; War has no decisions to make, so an entrant is
+/// identified by the seed it contributes to each of its deals
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entrant {
+    pub name: String,
+    pub seed: u64,
+}
+
+/// One game in the bracket, with entrants referenced by index
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatchResult {
+    /// Bracket round, starting at 1
+    pub round: usize,
+    pub player1: usize,
+    pub player2: usize,
+    pub winner: usize,
+    pub summary: GameSummary,
+}
+
+/// A completed single-elimination bracket
+#[derive(Debug, Clone)]
+pub struct Bracket {
+    pub entrants: Vec<Entrant>,
+    pub matches: Vec<MatchResult>,
+    /// Index of the winning entrant
+    pub champion: usize,
+}
+
+impl Bracket {
+    pub fn champion(&self) -> &Entrant {
+        &self.entrants[self.champion]
+    }
+}
+
+/// Deal seed for a match: depends on both entrants and on who plays first
+fn match_seed(player1: &Entrant, player2: &Entrant) -> u64 {
+    player1.seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ player2.seed
+}
+
+/// Play a single-elimination bracket, pairing neighbours each round
+/// The entrant count must be a power of two (at least 2); a game that ends tied
+/// on the round limit advances the first-listed entrant
+pub fn run_bracket(
+    entrants: Vec<Entrant>,
+    config: GameConfig,
+    max_rounds: usize,
+) -> GameResult<Bracket> {
+    if entrants.len() < 2 || !entrants.len().is_power_of_two() {
+        return Err(GameError::InvalidConfig(format!(
+            "a bracket needs a power of two entrants, got {}",
+            entrants.len()
+        )));
+    }
+
+    let mut alive: Vec<usize> = (0..entrants.len()).collect();
+    let mut matches = Vec::with_capacity(entrants.len() - 1);
+    let mut round = 1;
+
+    while alive.len() > 1 {
+        let mut next = Vec::with_capacity(alive.len() / 2);
+        for pair in alive.chunks_exact(2) {
+            let (player1, player2) = (pair[0], pair[1]);
+            let summary = simulate_with(
+                match_seed(&entrants[player1], &entrants[player2]),
+                config,
+                max_rounds,
+            );
+            let winner = match summary.winner {
+                Some(2) => player2,
+                _ => player1,
+            };
+            matches.push(MatchResult {
+                round,
+                player1,
+                player2,
+                winner,
+                summary,
+            });
+            next.push(winner);
+        }
+        alive = next;
+        round += 1;
+    }
+
+    Ok(Bracket {
+        entrants,
+        matches,
+        champion: alive[0],
+    })
+}
+
+impl fmt::Display for Bracket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut current_round = 0;
+        for result in &self.matches {
+            if result.round != current_round {
+                current_round = result.round;
+                writeln!(f, "Round {}", current_round)?;
+            }
+            writeln!(
+                f,
+                "  {} vs {} -> {} ({} rounds)",
+                self.entrants[result.player1].name,
+                self.entrants[result.player2].name,
+                self.entrants[result.winner].name,
+                result.summary.rounds
+            )?;
+        }
+        write!(f, "🏆 Champion: {}", self.champion().name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::DEFAULT_MAX_ROUNDS;
+
+    fn entrants(seeds: &[u64]) -> Vec<Entrant> {
+        seeds
+            .iter()
+            .enumerate()
+            .map(|(i, &seed)| Entrant {
+                name: format!("P{}", i + 1),
+                seed,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_four_player_bracket() {
+        let bracket = run_bracket(
+            entrants(&[1, 2, 3, 4]),
+            GameConfig::default(),
+            DEFAULT_MAX_ROUNDS,
+        )
+        .unwrap();
+
+        assert_eq!(bracket.matches.len(), 3);
+        assert_eq!(bracket.matches[2].round, 2);
+        // The final is between the two semi-final winners
+        let finalists = (bracket.matches[2].player1, bracket.matches[2].player2);
+        assert_eq!(
+            finalists,
+            (bracket.matches[0].winner, bracket.matches[1].winner)
+        );
+        assert_eq!(bracket.champion, bracket.matches[2].winner);
+        assert_eq!(bracket.champion().name, "P1");
+
+        let rerun = run_bracket(
+            entrants(&[1, 2, 3, 4]),
+            GameConfig::default(),
+            DEFAULT_MAX_ROUNDS,
+        )
+        .unwrap();
+        assert_eq!(rerun.matches, bracket.matches);
+
+        let printed = bracket.to_string();
+        assert!(printed.contains("Round 2"));
+        assert!(printed.ends_with("Champion: P1"));
+    }
+
+    #[test]
+    fn test_bracket_requires_power_of_two() {
+        let result = run_bracket(
+            entrants(&[1, 2, 3]),
+            GameConfig::default(),
+            DEFAULT_MAX_ROUNDS,
+        );
+        assert!(matches!(result, Err(GameError::InvalidConfig(_))));
+    }
+}
@@ -0,0 +1,162 @@
+use crate::cards::{Card, Deck};
+use crate::game::{GameConfig, GameResult};
+use crate::ring_buffer::RingBuffer;
+use crate::simulation::Simulation;
+use ratatui::Frame;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::widgets::{Block, Gauge, List, Paragraph};
+
+/// Rounds kept in the scrolling log
+const LOG_LEN: usize = 100;
+
+/// Everything the full-screen interface shows, driven one round at a time
+#[derive(Debug)]
+pub struct TuiApp {
+    simulation: Simulation,
+    /// The opening cards of the latest round
+    played: Option<(Card, Card)>,
+    /// The newest `LOG_LEN` round lines; older ones are evicted
+    log: RingBuffer<String, LOG_LEN>,
+}
+
+impl TuiApp {
+    /// Deal a seeded game under the rules in `config`
+    pub fn new(seed: u64, config: GameConfig) -> Self {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(seed);
+        TuiApp {
+            simulation: Simulation::from_deck(deck, config),
+            played: None,
+            log: RingBuffer::new(),
+        }
+    }
+
+    /// Play the next round, if the game isn't over
+    pub fn advance(&mut self) {
+        let cards = self.simulation.next_cards();
+        let Some(event) = self.simulation.step() else {
+            return;
+        };
+
+        self.played = cards;
+        let war = if event.war { " after a war" } else { "" };
+        self.log.push_back_overwrite(format!(
+            "Round {}: Player {} takes {} cards{}",
+            event.round, event.winner, event.pot_size, war
+        ));
+    }
+
+    /// Draw card-count gauges, the cards in play and the round log
+    pub fn render(&self, frame: &mut Frame) {
+        let [gauges, table, log] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Min(3),
+        ])
+        .areas(frame.area());
+        let [left, right] = Layout::horizontal([Constraint::Percentage(50); 2]).areas(gauges);
+
+        let (count1, count2) = self.simulation.card_counts();
+        for (area, player, count) in [(left, 1, count1), (right, 2, count2)] {
+            let gauge = Gauge::default()
+                .block(Block::bordered().title(format!("Player {}", player)))
+                .ratio(count as f64 / 52.0)
+                .label(format!("{} cards", count));
+            frame.render_widget(gauge, area);
+        }
+
+        let status = match (self.simulation.winner(), self.played) {
+            (Some(winner), _) => format!("🎉 Player {} wins! Press q to quit", winner),
+            (None, _) if self.simulation.is_finished() => {
+                "🤝 It's a draw! Press q to quit".to_string()
+            }
+            (None, Some((card1, card2))) => format!("{}  vs  {}", card1, card2),
+            (None, None) => "Press SPACE to play a round, q to quit".to_string(),
+        };
+        let title = format!("Round {}", self.simulation.round());
+        frame.render_widget(
+            Paragraph::new(status).block(Block::bordered().title(title)),
+            table,
+        );
+
+        // Newest first, so the latest round is always visible
+        let (older, newer) = self.log.as_slices();
+        let lines = older.iter().chain(newer).rev().map(String::as_str);
+        frame.render_widget(List::new(lines).block(Block::bordered().title("Log")), log);
+    }
+}
+
+/// Run the interface on the real terminal until the user quits
+pub fn run(seed: u64, config: GameConfig) -> GameResult<()> {
+    let mut app = TuiApp::new(seed, config);
+    let mut terminal = ratatui::init();
+
+    let result = (|| -> GameResult<()> {
+        loop {
+            terminal.draw(|frame| app.render(frame))?;
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match key.code {
+                    KeyCode::Char(' ') => app.advance(),
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                    _ => {}
+                }
+            }
+        }
+    })();
+
+    ratatui::restore();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+
+    fn rendered(app: &TuiApp) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(60, 14)).unwrap();
+        terminal.draw(|frame| app.render(frame)).unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_render_shows_game_state() {
+        let mut app = TuiApp::new(42, GameConfig::default());
+        let start = rendered(&app);
+        assert!(start.contains("Player 1") && start.contains("26 cards"));
+
+        app.advance();
+        let after = rendered(&app);
+        assert!(after.contains("Round 1"));
+        assert!(after.contains("Round 1: Player"));
+    }
+
+    #[test]
+    fn test_log_keeps_only_the_newest_rounds() {
+        // Seed 2 runs for hundreds of rounds
+        let mut app = TuiApp::new(2, GameConfig::default());
+        for _ in 0..LOG_LEN + 5 {
+            app.advance();
+        }
+
+        assert_eq!(app.log.len(), LOG_LEN);
+        assert!(app.log.get_ref(0).unwrap().starts_with("Round 6:"));
+        assert!(
+            app.log
+                .get_ref(LOG_LEN - 1)
+                .unwrap()
+                .starts_with("Round 105:")
+        );
+    }
+}
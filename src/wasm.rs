@@ -0,0 +1,9 @@
+use crate::game::DEFAULT_MAX_ROUNDS;
+use crate::simulation::simulate;
+use wasm_bindgen::prelude::*;
+
+/// Play a full seeded game and describe the result, for calling from JavaScript
+#[wasm_bindgen]
+pub fn play_seed(seed: u64) -> String {
+    simulate(seed, DEFAULT_MAX_ROUNDS).to_string()
+}
@@ -0,0 +1,39 @@
+//! Regression guard for the core rules: a fixed set of seeded games must keep
+//! producing exactly the outcomes recorded in `tests/golden/outcomes.json`
+//!
+//! After an intentional rule change, regenerate the file with
+//! `UPDATE_GOLDEN=1 cargo test --test golden_outcomes` and review the diff
+
+use std::fs;
+use std::path::Path;
+use war_rust::{DEFAULT_MAX_ROUNDS, simulate};
+
+const GOLDEN_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden/outcomes.json");
+
+/// One JSON object per line, so a changed outcome shows up as a one-line diff
+fn outcomes() -> String {
+    let lines: Vec<String> = (0..32)
+        .map(|seed| {
+            let summary = simulate(seed, DEFAULT_MAX_ROUNDS);
+            format!("  {{\"seed\":{},\"outcome\":{}}}", seed, summary.to_json())
+        })
+        .collect();
+    format!("[\n{}\n]\n", lines.join(",\n"))
+}
+
+#[test]
+fn test_outcomes_match_golden_file() {
+    let actual = outcomes();
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(GOLDEN_PATH, &actual).expect("failed to write golden file");
+        return;
+    }
+
+    let expected = fs::read_to_string(Path::new(GOLDEN_PATH))
+        .expect("missing golden file; run with UPDATE_GOLDEN=1 to create it");
+    for (line, (expected, actual)) in expected.lines().zip(actual.lines()).enumerate() {
+        assert_eq!(expected, actual, "outcome changed on line {}", line + 1);
+    }
+    assert_eq!(expected.lines().count(), actual.lines().count());
+}
@@ -0,0 +1,34 @@
+//! `--quiet` must print the final result and nothing else, so large batches
+//! of games can be run from scripts without drowning in narration
+
+use std::io;
+use std::process::Command;
+use war_rust::{Deck, GameConfig, NullObserver, WarGame};
+
+#[test]
+fn test_quiet_prints_only_the_result_line() {
+    let output = Command::new(env!("CARGO_BIN_EXE_war-rust"))
+        .args(["--quiet", "--seed", "42"])
+        .output()
+        .expect("failed to run war-rust");
+    assert!(output.status.success());
+
+    // The same deal, played silently in-process
+    let mut deck = Deck::new();
+    deck.shuffle_with_seed(42);
+    let mut game = WarGame::from_deck(deck, GameConfig::default(), io::empty());
+    game.set_observer(NullObserver);
+    game.play().unwrap();
+    let outcome = game.outcome().unwrap();
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(
+        stdout,
+        format!(
+            "Player {} wins after {} rounds ({} wars)\n",
+            outcome.winner.unwrap(),
+            outcome.rounds,
+            game.stats().wars
+        )
+    );
+}
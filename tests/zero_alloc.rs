@@ -0,0 +1,71 @@
+//! Enforces the "zero heap allocations" claim: once a game is dealt, playing it
+//! must never touch the allocator
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use war_rust::{DEFAULT_MAX_ROUNDS, Deck, GameConfig, GameEngine, Simulation, StepResult};
+
+/// Forwards to the system allocator, counting calls made on the current thread
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(|count| count.get())
+}
+
+#[test]
+fn test_full_game_does_not_allocate() {
+    for seed in [1, 42, 12345] {
+        // Setup (shuffling, dealing) is excluded from the count
+        let mut simulation = Simulation::new(seed);
+
+        let before = allocations();
+        let summary = simulation.run(DEFAULT_MAX_ROUNDS);
+        let during = allocations() - before;
+
+        assert!(summary.rounds > 0);
+        assert_eq!(during, 0, "seed {} allocated {} times", seed, during);
+    }
+}
+
+#[test]
+fn test_engine_steps_do_not_allocate() {
+    for seed in [1, 42, 12345] {
+        let mut deck = Deck::new();
+        deck.shuffle_with_seed(seed);
+        let mut engine = GameEngine::from_deck(deck, GameConfig::default());
+
+        let before = allocations();
+        let outcome = loop {
+            if let StepResult::Finished(outcome) = engine.step().unwrap() {
+                break outcome;
+            }
+        };
+        let during = allocations() - before;
+
+        assert!(outcome.rounds > 0);
+        assert_eq!(during, 0, "seed {} allocated {} times", seed, during);
+    }
+}